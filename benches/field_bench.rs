@@ -1,7 +1,7 @@
 // benches/field_bench.rs
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use stark_101::fields::FieldElement; // Update this if your crate name is different
+use stark_101::fields::{BabyBear, BinaryField128b, BinaryField32b, Field, FieldElement, Goldilocks, KoalaBear, Mersenne31, MontFieldElement, Stark252}; // Update this if your crate name is different
 
 fn bench_field_operations(c: &mut Criterion) {
     const MODULUS: u64 = 2147483647;
@@ -34,6 +34,99 @@ fn bench_field_operations(c: &mut Criterion) {
     });
 }
 
+/// Same operations on the Montgomery backend, reported next to the plain ones.
+fn bench_montgomery_operations(c: &mut Criterion) {
+    const MODULUS: u64 = 2147483647;
+
+    let a_el = MontFieldElement::<MODULUS>::new(3);
+    let b_el = MontFieldElement::<MODULUS>::new(5);
+
+    c.bench_function("mont_field_addition", |bencher| {
+        bencher.iter(|| {
+            black_box(black_box(a_el) + black_box(b_el));
+        })
+    });
+
+    c.bench_function("mont_field_multiplication", |bencher| {
+        bencher.iter(|| {
+            black_box(black_box(a_el) * black_box(b_el));
+        })
+    });
+
+    c.bench_function("mont_field_exponentiation", |bencher| {
+        bencher.iter(|| {
+            black_box(black_box(a_el).pow(10));
+        })
+    });
+
+    c.bench_function("mont_field_inverse", |bencher| {
+        bencher.iter(|| {
+            black_box(black_box(a_el).inverse());
+        })
+    });
+}
+
+
+/// Multiplication and inversion on the specialised fields.
+fn bench_specialised_fields(c: &mut Criterion) {
+    macro_rules! bench_field {
+        ($name:literal, $a:expr, $b:expr) => {
+            let a_el = $a;
+            let b_el = $b;
+
+            c.bench_function(concat!($name, "_multiplication"), |bencher| {
+                bencher.iter(|| {
+                    black_box(black_box(a_el) * black_box(b_el));
+                })
+            });
+
+            c.bench_function(concat!($name, "_inverse"), |bencher| {
+                bencher.iter(|| {
+                    black_box(black_box(a_el).inverse());
+                })
+            });
+        };
+        ($name:literal, $fe:ty) => {
+            bench_field!($name, <$fe>::new(3), <$fe>::new(5));
+        };
+    }
+
+    bench_field!("goldilocks", Goldilocks);
+    bench_field!("baby_bear", BabyBear);
+    bench_field!("koala_bear", KoalaBear);
+    bench_field!("mersenne31", Mersenne31);
+    bench_field!("binary32", BinaryField32b);
+    bench_field!("binary128", BinaryField128b);
+    bench_field!("stark252", Stark252::from_u64(3), Stark252::from_u64(5));
+}
+
+/// Element-wise slice products: plain loop vs the vectorised `Field::mul_slices`.
+fn bench_slice_operations(c: &mut Criterion) {
+    const MODULUS: u64 = 0x7800_0001;
+    const N: usize = 1 << 14;
+
+    let a: Vec<FieldElement<MODULUS>> = (0..N as u64).map(FieldElement::new).collect();
+    let b: Vec<FieldElement<MODULUS>> = (0..N as u64).map(|i| FieldElement::new(i * 7 + 1)).collect();
+
+    c.bench_function("slice_mul_loop", |bencher| {
+        bencher.iter(|| {
+            let mut out = a.clone();
+            for (x, y) in out.iter_mut().zip(&b) {
+                *x *= *y;
+            }
+            black_box(out);
+        })
+    });
+
+    c.bench_function("slice_mul_packed", |bencher| {
+        bencher.iter(|| {
+            let mut out = a.clone();
+            FieldElement::mul_slices(&mut out, &b);
+            black_box(out);
+        })
+    });
+}
+
 
-criterion_group!(benches, bench_field_operations);
+criterion_group!(benches, bench_field_operations, bench_montgomery_operations, bench_specialised_fields, bench_slice_operations);
 criterion_main!(benches);
\ No newline at end of file
@@ -1,7 +1,9 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
 use stark_101::fields::FieldElement;
+use stark_101::polynomial::{CosetDomain, Polynomial};
 use stark_101::polynomial::interpolation::{gen_lagrange_polynomials_parallel,interpolate_lagrange_polynomials};
-use stark_101::polynomial::Polynomial;
 
 
 /// Benchmark Lagrange polynomial generation
@@ -13,14 +15,15 @@ fn bench_lagrange_polynomials(c: &mut Criterion) {
         // Prepare a group so Criterion can plot each size separately
         let mut group = c.benchmark_group("gen_lagrange_polynomials:");
 
-        // Generate random x values
-        let xs: Vec<FieldElement<7>> = (0..size).map(|_| FieldElement::<7>::random()).collect();
+        // Random x values, distinct with overwhelming probability in a 31-bit field
+        let mut rng = ChaCha20Rng::seed_from_u64(size as u64);
+        let xs: Vec<FieldElement<2013265921>> = (0..size).map(|_| FieldElement::<2013265921>::random_with(&mut rng)).collect();
         
         // Benchmark a closure that calls `gen_lagrange_polynomials`
         group.bench_function(format!(" n={}", size), |b| {
             b.iter(|| {
                 // Use black_box(...) to prevent compiler optimizations
-                black_box(gen_lagrange_polynomials_parallel(black_box(&xs)));
+                black_box(gen_lagrange_polynomials_parallel(black_box(&xs)).unwrap());
             });
         });
 
@@ -29,20 +32,20 @@ fn bench_lagrange_polynomials(c: &mut Criterion) {
 }
 
 /// Benchmark Lagrange interpolation
-
 fn bench_interpolate_lagrange(c: &mut Criterion) {
     let sizes = [10, 50, 100, 200, 500];
 
     for &size in &sizes {
         let mut group = c.benchmark_group("interpolate_lagrange_polynomials");
 
-        // Generate random (xs, ys) pairs using the implemented `random()`
-        let xs: Vec<FieldElement<7>> = (0..size).map(|_| FieldElement::<7>::random()).collect();
-        let ys: Vec<FieldElement<7>> = (0..size).map(|_| FieldElement::<7>::random()).collect();
+        // Generate random (xs, ys) pairs from a fixed seed
+        let mut rng = ChaCha20Rng::seed_from_u64(size as u64);
+        let xs: Vec<FieldElement<2013265921>> = (0..size).map(|_| FieldElement::<2013265921>::random_with(&mut rng)).collect();
+        let ys: Vec<FieldElement<2013265921>> = (0..size).map(|_| FieldElement::<2013265921>::random_with(&mut rng)).collect();
 
         group.bench_function(format!("n={}", size), |b| {
             b.iter(|| {
-                black_box(interpolate_lagrange_polynomials(black_box(&xs), black_box(&ys)));
+                black_box(interpolate_lagrange_polynomials(black_box(&xs), black_box(&ys)).unwrap());
             });
         });
 
@@ -51,10 +54,42 @@ fn bench_interpolate_lagrange(c: &mut Criterion) {
 }
 
 
+/// Subproduct-tree interpolation at random points and inverse-NTT interpolation
+/// on a subgroup, both through `Polynomial::interpolate`.
+fn bench_interpolate_fast(c: &mut Criterion) {
+    let sizes = [10, 50, 100, 200, 500, 4096];
+
+    for &size in &sizes {
+        let mut group = c.benchmark_group("interpolate_fast");
+
+        let mut rng = ChaCha20Rng::seed_from_u64(size as u64);
+        let xs: Vec<FieldElement<2013265921>> = (0..size).map(|_| FieldElement::<2013265921>::random_with(&mut rng)).collect();
+        let ys: Vec<FieldElement<2013265921>> = (0..size).map(|_| FieldElement::<2013265921>::random_with(&mut rng)).collect();
+
+        group.bench_function(format!("tree n={}", size), |b| {
+            b.iter(|| {
+                black_box(Polynomial::interpolate(black_box(&xs), black_box(&ys)).unwrap());
+            });
+        });
+
+        let n = (size as usize).next_power_of_two();
+        let subgroup = CosetDomain::<FieldElement<2013265921>>::subgroup(n).unwrap().elements();
+        let values: Vec<FieldElement<2013265921>> = (0..n).map(|_| FieldElement::<2013265921>::random_with(&mut rng)).collect();
+        group.bench_function(format!("subgroup n={}", n), |b| {
+            b.iter(|| {
+                black_box(Polynomial::interpolate(black_box(&subgroup), black_box(&values)).unwrap());
+            });
+        });
+
+        group.finish();
+    }
+}
+
 // The Criterion macros that define the main entry point for `cargo bench`
 criterion_group!(
     benches,
     bench_lagrange_polynomials,
-    bench_interpolate_lagrange
+    bench_interpolate_lagrange,
+    bench_interpolate_fast
 );
 criterion_main!(benches);
@@ -1,11 +1,12 @@
 #![cfg_attr(feature = "nightly", feature(concat_idents))]
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, SamplingMode};
-use rand::{RngCore, SeedableRng};
+use rand::SeedableRng;
 use rand_chacha::ChaCha20Rng;
 
 
-use stark_101::fields::FieldElement;
-use stark_101::polynomial::Polynomial;
+use stark_101::fields::{BabyBear, FieldElement, Goldilocks, KoalaBear, Mersenne31, MontFieldElement};
+use stark_101::polynomial::tree::evaluate_many;
+use stark_101::polynomial::{div, mul, ntt, Polynomial, SubproductTree};
 
 
 // const PRIME_MODULI: &[u64] = &[
@@ -16,20 +17,19 @@ use stark_101::polynomial::Polynomial;
 // ];
 
 macro_rules! define_benches_for_modulus {
-    ($modulus:expr, $mod_name:ident) => {
+    ($fe:ty, $mod_name:ident) => {
         mod $mod_name {
             use super::*;
-            type FE = FieldElement<$modulus>;
-            type Poly = Polynomial<$modulus>;
+            type FE = $fe;
+            type Poly = Polynomial<FE>;
 
             /// Generate a random FieldElement
             //use a cryto safe random no generator
             fn random_fe(rng: &mut ChaCha20Rng) -> FE {
-                let val = rng.next_u64() % $modulus;
-                FE::new(val)
+                FE::random_with(rng)
             }
 
-            /// Generate a random Polynomial<$modulus> of given degree
+            /// Generate a random Polynomial<FE> of given degree
             fn random_poly(rng: &mut ChaCha20Rng, degree: usize) -> Poly {
                 let coeffs = (0..=degree).map(|_| random_fe(rng)).collect();
                 Poly::new(coeffs)
@@ -37,7 +37,7 @@ macro_rules! define_benches_for_modulus {
 
             /// Benchmark Polynomial Addition
             pub fn bench_add(c: &mut Criterion) {
-                let mut group = c.benchmark_group(concat!("Add_", stringify!($modulus)));
+                let mut group = c.benchmark_group(concat!("Add_", stringify!($mod_name)));
                 group.sampling_mode(SamplingMode::Flat);
 
                 let sizes = [10, 100, 1_000];
@@ -60,7 +60,7 @@ macro_rules! define_benches_for_modulus {
 
             /// Benchmark Polynomial Subtraction
             pub fn bench_sub(c: &mut Criterion) {
-                let mut group = c.benchmark_group(concat!("Sub_", stringify!($modulus)));
+                let mut group = c.benchmark_group(concat!("Sub_", stringify!($mod_name)));
                 group.sampling_mode(SamplingMode::Flat);
 
                 let sizes = [10, 100, 1_000];
@@ -83,7 +83,7 @@ macro_rules! define_benches_for_modulus {
 
             /// Benchmark Polynomial Multiplication
             pub fn bench_mul(c: &mut Criterion) {
-                let mut group = c.benchmark_group(concat!("Mul_", stringify!($modulus)));
+                let mut group = c.benchmark_group(concat!("Mul_", stringify!($mod_name)));
                 group.sampling_mode(SamplingMode::Flat);
 
                 let sizes = [10, 100, 1_000];
@@ -106,7 +106,7 @@ macro_rules! define_benches_for_modulus {
 
             /// Benchmark Polynomial Division with Remainder
             pub fn bench_div_rem(c: &mut Criterion) {
-                let mut group = c.benchmark_group(concat!("DivRem_", stringify!($modulus)));
+                let mut group = c.benchmark_group(concat!("DivRem_", stringify!($mod_name)));
                 group.sampling_mode(SamplingMode::Flat);
 
                 let sizes = [10, 100, 1_000];
@@ -135,7 +135,7 @@ macro_rules! define_benches_for_modulus {
 
             /// Benchmark Polynomial Composition
             pub fn bench_compose(c: &mut Criterion) {
-                let mut group = c.benchmark_group(concat!("Compose_", stringify!($modulus)));
+                let mut group = c.benchmark_group(concat!("Compose_", stringify!($mod_name)));
                 group.sampling_mode(SamplingMode::Flat);
 
                 let sizes = [10, 50, 100];
@@ -159,7 +159,7 @@ macro_rules! define_benches_for_modulus {
 
             /// Benchmark Polynomial Evaluation
             pub fn bench_eval(c: &mut Criterion) {
-                let mut group = c.benchmark_group(concat!("Eval_", stringify!($modulus)));
+                let mut group = c.benchmark_group(concat!("Eval_", stringify!($mod_name)));
                 group.sampling_mode(SamplingMode::Flat);
 
                 let sizes = [10, 100, 1_000, 5_000];
@@ -182,7 +182,7 @@ macro_rules! define_benches_for_modulus {
 
             /// Benchmark Polynomial AddAssign
             pub fn bench_add_assign(c: &mut Criterion) {
-                let mut group = c.benchmark_group(concat!("AddAssign_", stringify!($modulus)));
+                let mut group = c.benchmark_group(concat!("AddAssign_", stringify!($mod_name)));
                 group.sampling_mode(SamplingMode::Flat);
 
                 let sizes = [10, 100, 1_000];
@@ -205,7 +205,7 @@ macro_rules! define_benches_for_modulus {
 
             /// Benchmark Polynomial MulAssign
             pub fn bench_mul_assign(c: &mut Criterion) {
-                let mut group = c.benchmark_group(concat!("MulAssign_", stringify!($modulus)));
+                let mut group = c.benchmark_group(concat!("MulAssign_", stringify!($mod_name)));
                 group.sampling_mode(SamplingMode::Flat);
                 group.measurement_time(std::time::Duration::from_secs(8)); 
 
@@ -227,6 +227,88 @@ macro_rules! define_benches_for_modulus {
                 group.finish();
             }
 
+            /// Schoolbook vs Karatsuba vs NTT product of two equal-length coefficient vectors,
+            /// to locate `mul::KARATSUBA_THRESHOLD` and `mul::NTT_THRESHOLD`.
+            /// Fields without the roots of unity skip the NTT.
+            pub fn bench_mul_crossover(c: &mut Criterion) {
+                let mut group = c.benchmark_group(concat!("MulCrossover_", stringify!($mod_name)));
+                group.sampling_mode(SamplingMode::Flat);
+
+                let sizes = [16, 32, 64, 128, 256, 512, 2048];
+                let mut rng = ChaCha20Rng::seed_from_u64(1818);
+
+                for &size in &sizes {
+                    let a: Vec<FE> = (0..size).map(|_| random_fe(&mut rng)).collect();
+                    let b: Vec<FE> = (0..size).map(|_| random_fe(&mut rng)).collect();
+
+                    group.bench_with_input(BenchmarkId::new("schoolbook", size), &size, |bencher, &_size| {
+                        bencher.iter(|| black_box(mul::schoolbook(black_box(&a), black_box(&b))))
+                    });
+                    group.bench_with_input(BenchmarkId::new("karatsuba", size), &size, |bencher, &_size| {
+                        bencher.iter(|| black_box(mul::karatsuba(black_box(&a), black_box(&b))))
+                    });
+                    if ntt::multiply(&a, &b).is_some() {
+                        group.bench_with_input(BenchmarkId::new("ntt", size), &size, |bencher, &_size| {
+                            bencher.iter(|| black_box(ntt::multiply(black_box(&a), black_box(&b))))
+                        });
+                    }
+                }
+
+                group.finish();
+            }
+
+            /// Long division vs Newton-iteration division of a degree-2k polynomial
+            /// by a degree-k one, to locate `div::FAST_DIV_THRESHOLD`.
+            pub fn bench_div_crossover(c: &mut Criterion) {
+                let mut group = c.benchmark_group(concat!("DivCrossover_", stringify!($mod_name)));
+                group.sampling_mode(SamplingMode::Flat);
+
+                let sizes = [32, 64, 128, 256, 512, 1024];
+                let mut rng = ChaCha20Rng::seed_from_u64(2020);
+
+                for &size in &sizes {
+                    let a = random_poly(&mut rng, 2 * size);
+                    let mut b = random_poly(&mut rng, size);
+                    if b.is_zero() {
+                        b = Poly::new(vec![FE::one()]);
+                    }
+
+                    group.bench_with_input(BenchmarkId::new("naive", size), &size, |bencher, &_size| {
+                        bencher.iter(|| black_box(black_box(&a).div_rem_naive(black_box(&b))))
+                    });
+                    group.bench_with_input(BenchmarkId::new("newton", size), &size, |bencher, &_size| {
+                        bencher.iter(|| black_box(div::div_rem_fast(black_box(&a.coefficients), black_box(&b.coefficients))))
+                    });
+                }
+
+                group.finish();
+            }
+
+            /// One Horner run per point vs the subproduct tree, for a degree-(n-1)
+            /// polynomial at n points. The tree is built outside the timed loop.
+            pub fn bench_evaluate_many(c: &mut Criterion) {
+                let mut group = c.benchmark_group(concat!("EvaluateMany_", stringify!($mod_name)));
+                group.sampling_mode(SamplingMode::Flat);
+
+                let sizes = [256, 1024, 4096];
+                let mut rng = ChaCha20Rng::seed_from_u64(2222);
+
+                for &size in &sizes {
+                    let p = random_poly(&mut rng, size - 1);
+                    let points: Vec<FE> = (0..size).map(|_| random_fe(&mut rng)).collect();
+                    let tree = SubproductTree::new(&points);
+
+                    group.bench_with_input(BenchmarkId::new("horner", size), &size, |bencher, &_size| {
+                        bencher.iter(|| black_box(points.iter().map(|x| p.evaluate(*x)).collect::<Vec<FE>>()))
+                    });
+                    group.bench_with_input(BenchmarkId::new("tree", size), &size, |bencher, &_size| {
+                        bencher.iter(|| black_box(evaluate_many(black_box(&p), black_box(&tree))))
+                    });
+                }
+
+                group.finish();
+            }
+
             criterion_group!(
                 $mod_name,
                 bench_add,
@@ -236,12 +318,28 @@ macro_rules! define_benches_for_modulus {
                 bench_compose,
                 bench_eval,
                 bench_add_assign,
-                bench_mul_assign
+                bench_mul_assign,
+                bench_mul_crossover,
+                bench_div_crossover,
+                bench_evaluate_many
             );
         }
-        criterion_main!($mod_name::$mod_name);
     }}
 
-    define_benches_for_modulus!{17, benches_17}
+    define_benches_for_modulus!{FieldElement<17>, benches_17}
+    define_benches_for_modulus!{MontFieldElement<17>, benches_mont_17}
+    define_benches_for_modulus!{BabyBear, benches_baby_bear}
+    define_benches_for_modulus!{KoalaBear, benches_koala_bear}
+    define_benches_for_modulus!{Mersenne31, benches_mersenne31}
+    define_benches_for_modulus!{Goldilocks, benches_goldilocks}
+
+criterion_main!(
+    benches_17::benches_17,
+    benches_mont_17::benches_mont_17,
+    benches_baby_bear::benches_baby_bear,
+    benches_koala_bear::benches_koala_bear,
+    benches_mersenne31::benches_mersenne31,
+    benches_goldilocks::benches_goldilocks
+);
 
 // define_benches_for_modulus!(17,benches_17);
@@ -2,30 +2,39 @@
 // use merlin ??
 
 
-use crate::fields::FieldElement; 
+use crate::fields::{ExtensionOf, Field};
 use alloy::{hex::{self}, primitives::U256};
 use sha256;
+use std::marker::PhantomData;
 
 
 /// - `proof`: stores all messages (Prover + “random challenges”).
 /// - `compressed_proof`: stores a subset
 /// - `state`: a rolling state  to generate pseudo-randomness.
 #[derive(Debug, Clone)]
-pub struct Channel<const MODULUS: u64> {
+pub struct Channel<F> {
     /// All messages in raw bytes
     pub proof: Vec<Vec<u8>>,
     pub compressed_proof: Vec<Vec<u8>>,
     /// Current "randomness" state, stored as hex for naive hashing.
     pub state: String,
+    _field: PhantomData<F>,
 }
 
-impl<const MODULUS: u64> Channel<MODULUS> {
+impl<F: Field> Default for Channel<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: Field> Channel<F> {
 
     pub fn new() -> Self {
         Self {
             proof: Vec::new(),
             compressed_proof: Vec::new(),
-            state: String::new()
+            state: String::new(),
+            _field: PhantomData,
         }
     }
 
@@ -44,16 +53,32 @@ impl<const MODULUS: u64> Channel<MODULUS> {
     }
 
 
-    pub fn receive_random_field_element(&mut self) -> FieldElement<MODULUS> {
-        let num = self.receive_random_int(0, (MODULUS - 1) as usize, false);
-        let field_elem = FieldElement::new(num as u64);
+    /// Reduces the current state modulo the field characteristic, so any field
+    /// (including ones wider than `usize`) can be sampled.
+    pub fn receive_random_field_element(&mut self) -> F {
+        let state_u256 = U256::from_str_radix(&self.state, 16)
+            .expect("Channel state is not valid hex");
+        let field_elem = F::from_be_bytes_mod_order(&state_u256.to_be_bytes::<32>());
+
+        // Update the channel's state with another hash.
+        let old_state = self.state.clone();
+        self.state = sha256::digest(old_state);
 
         //store the numeric value in the full proof (as bytes).
-        self.proof.push(num.to_be_bytes().to_vec());
+        self.proof.push(field_elem.to_bytes());
 
         field_elem
     }
 
+    /// Draws an extension-field challenge as `E::DEGREE` consecutive base-field
+    /// draws, so its soundness is `1 / p^DEGREE` rather than `1 / p`.
+    pub fn receive_random_extension_element<E: ExtensionOf<F>>(&mut self) -> E {
+        let coeffs: Vec<F> = (0..E::DEGREE)
+            .map(|_| self.receive_random_field_element())
+            .collect();
+        E::from_base_coeffs(&coeffs)
+    }
+
     /// Emulates receiving a random integer in [min..max].
     pub fn receive_random_int(&mut self, min: usize, max: usize, show_in_proof: bool) -> usize {
 
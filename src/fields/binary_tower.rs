@@ -0,0 +1,627 @@
+//! Binary tower fields `GF(2^(2^K))` (Wiedemann's construction).
+//!
+//! `T_0 = GF(2)` and `T_{k+1} = T_k[X_k] / (X_k^2 + X_{k-1} X_k + 1)` with
+//! `X_{-1} = 1`. An element of `T_{k+1}` is `a_0 + a_1 X_k` with both halves in
+//! `T_k`, stored as `a_0 | a_1 << 2^k`, so every subfield sits in the low bits.
+//! Addition is XOR, so nothing ever carries.
+//!
+//! Multiplication on levels from [`CLMUL_MIN_LEVEL`] up maps both operands to a
+//! polynomial basis `GF(2)[x] / P(x)` of the same field, takes their carry-less
+//! product (PCLMULQDQ / PMULL where available, see [`clmul`]), reduces it modulo
+//! `P` with Barrett's method and maps the result back. `x` corresponds to the
+//! top tower generator and `P` is its minimal polynomial, so both basis changes
+//! are fixed `GF(2)`-linear maps, applied through nibble tables built on first
+//! use. The smaller levels, and the table construction itself, use the tower's
+//! own Karatsuba recursion with three half-size products.
+
+use crate::fields::clmul;
+use crate::fields::{Field, FieldError};
+use alloy::primitives::U256;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use rand_core::{OsRng, RngCore};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::sync::OnceLock;
+use subtle::ConstantTimeEq;
+
+pub type BinaryField1b = BinaryTower<0>;
+pub type BinaryField2b = BinaryTower<1>;
+pub type BinaryField4b = BinaryTower<2>;
+pub type BinaryField8b = BinaryTower<3>;
+pub type BinaryField16b = BinaryTower<4>;
+pub type BinaryField32b = BinaryTower<5>;
+pub type BinaryField64b = BinaryTower<6>;
+pub type BinaryField128b = BinaryTower<7>;
+
+/// An element of the tower level `T_K`, which has `2^(2^K)` elements.
+#[derive(Debug, Clone, Copy)]
+pub struct BinaryTower<const K: u32> {
+    value: u128,
+}
+
+/// Lowest tower level whose multiplication goes through [`PolyBasis`]. Below
+/// it the recursion has at most 27 leaves and is cheaper than three basis changes.
+pub const CLMUL_MIN_LEVEL: u32 = 4;
+
+/// `a * b` in `T_level` by recursion down the tower; the reference for [`PolyBasis`].
+fn tower_mul(a: u128, b: u128, level: u32) -> u128 {
+    if level == 0 {
+        return a & b;
+    }
+    let half = 1u32 << (level - 1);
+    let mask = (1u128 << half) - 1;
+    let (a0, a1) = (a & mask, a >> half);
+    let (b0, b1) = (b & mask, b >> half);
+
+    let z0 = tower_mul(a0, b0, level - 1);
+    let z2 = tower_mul(a1, b1, level - 1);
+    let z1 = tower_mul(a0 ^ a1, b0 ^ b1, level - 1) ^ z0 ^ z2;
+    // X^2 = X_{level-2} X + 1
+    let lo = z0 ^ z2;
+    let hi = z1 ^ mul_by_generator(z2, level - 1);
+    lo | (hi << half)
+}
+
+/// `a * X_{level-1}` in `T_level` (with `X_{-1} = 1`).
+fn mul_by_generator(a: u128, level: u32) -> u128 {
+    if level == 0 {
+        return a;
+    }
+    let half = 1u32 << (level - 1);
+    let mask = (1u128 << half) - 1;
+    let (a0, a1) = (a & mask, a >> half);
+    // (a0 + a1 X) X = a1 + (a0 + a1 X_{level-2}) X
+    a1 | ((a0 ^ mul_by_generator(a1, level - 1)) << half)
+}
+
+/// `T_level` in the polynomial basis `GF(2)[x] / (x^n + modulus_low)`, `n = 2^level`,
+/// where `x` is the generator `X_{level-1}`.
+struct PolyBasis {
+    bits: u32,
+    /// `P(x) - x^n`.
+    modulus_low: u128,
+    /// `floor(x^(2n) / P) - x^n`, for Barrett reduction.
+    barrett_low: u128,
+    /// `to_poly[i][nibble]`: polynomial coordinates of tower bits `4i..4i+4` set as in `nibble`.
+    to_poly: Vec<[u128; 16]>,
+    /// `to_tower[i][nibble]`: the same for the way back.
+    to_tower: Vec<[u128; 16]>,
+}
+
+impl PolyBasis {
+    fn new(level: u32) -> Self {
+        let n = 1u32 << level;
+        let beta = 1u128 << (n / 2);
+        // beta lies in no proper subfield (those are the T_j, j < level), so
+        // 1, beta, ..., beta^(n-1) is a basis and beta^n is a combination of them.
+        let mut powers = Vec::with_capacity(n as usize + 1);
+        let mut power = 1u128;
+        for _ in 0..=n {
+            powers.push(power);
+            power = tower_mul(power, beta, level);
+        }
+
+        // Gauss-Jordan on (tower value, polynomial coordinates) pairs until row j
+        // holds the tower bit j alone; its coordinates are then the image of bit j.
+        let mut rows: Vec<(u128, u128)> = (0..n as usize).map(|i| (powers[i], 1u128 << i)).collect();
+        for j in 0..n as usize {
+            let pivot = (j..n as usize)
+                .find(|&r| (rows[r].0 >> j) & 1 == 1)
+                .expect("powers of the generator form a basis");
+            rows.swap(j, pivot);
+            let (t, p) = rows[j];
+            for (r, row) in rows.iter_mut().enumerate() {
+                if r != j && (row.0 >> j) & 1 == 1 {
+                    row.0 ^= t;
+                    row.1 ^= p;
+                }
+            }
+        }
+        let tower_to_poly: Vec<u128> = rows.iter().map(|&(_, p)| p).collect();
+        let modulus_low = apply_linear(&tower_to_poly, powers[n as usize]);
+
+        PolyBasis {
+            bits: n,
+            modulus_low,
+            barrett_low: barrett_low(n, modulus_low),
+            to_poly: nibble_tables(&tower_to_poly),
+            to_tower: nibble_tables(&powers[..n as usize]),
+        }
+    }
+
+    /// The `PolyBasis` of `T_level`, built on first use.
+    fn get(level: u32) -> &'static PolyBasis {
+        static BASES: [OnceLock<PolyBasis>; 8] = [const { OnceLock::new() }; 8];
+        BASES[level as usize].get_or_init(|| PolyBasis::new(level))
+    }
+
+    fn convert(tables: &[[u128; 16]], value: u128) -> u128 {
+        tables
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, table)| acc ^ table[((value >> (4 * i)) & 0xf) as usize])
+    }
+
+    /// Bits `n..2n` and `0..n` of the carry-less product of two `n`-bit values.
+    fn clmul_split(&self, a: u128, b: u128) -> (u128, u128) {
+        let n = self.bits;
+        if n == 128 {
+            let (lo, hi) = clmul::clmul128(a, b);
+            return (hi, lo);
+        }
+        let product = clmul::clmul64(a as u64, b as u64);
+        (product >> n, product & ((1u128 << n) - 1))
+    }
+
+    fn mul(&self, a: u128, b: u128) -> u128 {
+        let (a, b) = (Self::convert(&self.to_poly, a), Self::convert(&self.to_poly, b));
+        let (c_hi, c_lo) = self.clmul_split(a, b);
+        // q = floor(c / P) = c_hi + floor(c_hi * barrett_low / x^n); c mod P = c_lo + (q * modulus_low mod x^n).
+        let q = c_hi ^ self.clmul_split(c_hi, self.barrett_low).0;
+        let r = c_lo ^ self.clmul_split(q, self.modulus_low).1;
+        Self::convert(&self.to_tower, r)
+    }
+}
+
+/// `XOR` of `images[i]` over the set bits `i` of `value`.
+fn apply_linear(images: &[u128], value: u128) -> u128 {
+    images
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| (value >> i) & 1 == 1)
+        .fold(0, |acc, (_, image)| acc ^ image)
+}
+
+/// Lookup tables for the linear map with the given images of the basis bits, four bits at a time.
+fn nibble_tables(images: &[u128]) -> Vec<[u128; 16]> {
+    images
+        .chunks(4)
+        .map(|chunk| std::array::from_fn(|nibble| apply_linear(chunk, nibble as u128)))
+        .collect()
+}
+
+/// `floor(x^(2n) / P) - x^n` for `P = x^n + modulus_low`, by long division.
+fn barrett_low(n: u32, modulus_low: u128) -> u128 {
+    // After taking out x^n * P, the remainder is modulus_low * x^n, held as 256 bits.
+    let shl = |v: u128, k: u32| -> (u128, u128) {
+        match k {
+            0 => (v, 0),
+            k if k < 128 => (v << k, v >> (128 - k)),
+            k => (0, v << (k - 128)),
+        }
+    };
+    let (mut lo, mut hi) = shl(modulus_low, n);
+    let mut quotient = 0u128;
+    for k in (0..n).rev() {
+        let bit = n + k;
+        let set = if bit < 128 { (lo >> bit) & 1 } else { (hi >> (bit - 128)) & 1 };
+        if set == 1 {
+            quotient |= 1u128 << k;
+            // Subtract x^k * P = x^(n+k) + modulus_low * x^k.
+            let (x_lo, x_hi) = shl(1, bit);
+            let (m_lo, m_hi) = shl(modulus_low, k);
+            lo ^= x_lo ^ m_lo;
+            hi ^= x_hi ^ m_hi;
+        }
+    }
+    quotient
+}
+
+/// `a * b` in `T_level`, through [`PolyBasis`] from [`CLMUL_MIN_LEVEL`] up.
+fn mul_values(a: u128, b: u128, level: u32) -> u128 {
+    if level >= CLMUL_MIN_LEVEL {
+        PolyBasis::get(level).mul(a, b)
+    } else {
+        tower_mul(a, b, level)
+    }
+}
+
+/// `a^{-1}` in `T_level` for non-zero `a`, via the norm down to `T_{level-1}`:
+/// the conjugate of `a0 + a1 X` is `(a0 + a1 c) + a1 X` with `c = X_{level-2}`,
+/// and their product is `a0^2 + a0 a1 c + a1^2`.
+fn tower_inv(a: u128, level: u32) -> u128 {
+    if level == 0 {
+        return a;
+    }
+    let half = 1u32 << (level - 1);
+    let mask = (1u128 << half) - 1;
+    let (a0, a1) = (a & mask, a >> half);
+
+    let a1c = mul_by_generator(a1, level - 1);
+    let norm = mul_values(a0, a0, level - 1) ^ mul_values(a0, a1c, level - 1) ^ mul_values(a1, a1, level - 1);
+    let norm_inv = tower_inv(norm, level - 1);
+    let lo = mul_values(a0 ^ a1c, norm_inv, level - 1);
+    let hi = mul_values(a1, norm_inv, level - 1);
+    lo | (hi << half)
+}
+
+impl<const K: u32> BinaryTower<K> {
+    const VALID_LEVEL: () = assert!(K <= 7, "binary towers go up to GF(2^128)");
+
+    /// Number of bits in an element, `2^K`.
+    pub const BITS: u32 = 1 << K;
+
+    const MASK: u128 = if K == 7 { u128::MAX } else { (1u128 << (1u32 << K)) - 1 };
+
+    /// Keeps the low `2^K` bits of `value`.
+    pub fn new(value: u128) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID_LEVEL;
+        BinaryTower { value: value & Self::MASK }
+    }
+
+    pub fn zero() -> Self {
+        BinaryTower { value: 0 }
+    }
+
+    pub fn one() -> Self {
+        BinaryTower { value: 1 }
+    }
+
+    pub fn value(&self) -> u128 {
+        self.value
+    }
+
+    /// The generator `X_{K-1}` adjoined at the top level.
+    pub fn generator() -> Self {
+        assert!(K > 0, "GF(2) has no adjoined generator");
+        BinaryTower::new(1u128 << (Self::BITS / 2))
+    }
+
+    /// Views `self` as an element of the larger tower level `L >= K`.
+    pub fn embed<const L: u32>(self) -> BinaryTower<L> {
+        assert!(L >= K, "can only embed into a larger tower level");
+        BinaryTower { value: self.value }
+    }
+
+    pub fn random() -> Self {
+        Self::random_with(&mut OsRng)
+    }
+
+    /// Uniform sample: the low `2^K` bits of 128 random bits.
+    pub fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        let value = (rng.next_u64() as u128) << 64 | rng.next_u64() as u128;
+        BinaryTower::new(value)
+    }
+
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    pub fn pow(&self, exp: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = *self;
+        let mut e = exp;
+
+        while e > 0 {
+            if e & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        result
+    }
+
+    pub fn inverse(&self) -> Self {
+        assert!(self.value != 0, "Cannot invert zero in a binary field");
+        BinaryTower {
+            value: tower_inv(self.value, K),
+        }
+    }
+
+    /// Number of bytes in the encoding: one for the sub-byte levels.
+    const NUM_BYTES: usize = if K < 3 { 1 } else { 1 << (K - 3) };
+
+    /// Big-endian bytes of the value, `max(1, 2^K / 8)` of them.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.value.to_be_bytes()[16 - Self::NUM_BYTES..].to_vec()
+    }
+}
+
+impl<const K: u32> PartialEq for BinaryTower<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.ct_eq(&other.value).unwrap_u8() == 1
+    }
+}
+
+impl<const K: u32> Eq for BinaryTower<K> {}
+
+impl<const K: u32> Hash for BinaryTower<K> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<const K: u32> Add for BinaryTower<K> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self::Output {
+        BinaryTower {
+            value: self.value ^ rhs.value,
+        }
+    }
+}
+
+impl<const K: u32> AddAssign for BinaryTower<K> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// In characteristic 2 subtraction is addition.
+impl<const K: u32> Sub for BinaryTower<K> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + rhs
+    }
+}
+
+impl<const K: u32> SubAssign for BinaryTower<K> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const K: u32> Mul for BinaryTower<K> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        BinaryTower {
+            value: mul_values(self.value, rhs.value, K),
+        }
+    }
+}
+
+impl<const K: u32> MulAssign for BinaryTower<K> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const K: u32> Div for BinaryTower<K> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+impl<const K: u32> DivAssign for BinaryTower<K> {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self *= rhs.inverse();
+    }
+}
+
+/// Every element is its own additive inverse.
+impl<const K: u32> Neg for BinaryTower<K> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+impl<const K: u32> Distribution<BinaryTower<K>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BinaryTower<K> {
+        BinaryTower::random_with(rng)
+    }
+}
+
+impl<const K: u32> Field for BinaryTower<K> {
+    fn zero() -> Self {
+        BinaryTower::zero()
+    }
+
+    fn one() -> Self {
+        BinaryTower::one()
+    }
+
+    /// The integer `n` maps to `n mod 2`.
+    fn from_u64(value: u64) -> Self {
+        BinaryTower {
+            value: (value & 1) as u128,
+        }
+    }
+
+    fn inverse(&self) -> Self {
+        BinaryTower::inverse(self)
+    }
+
+    fn pow(&self, exp: u64) -> Self {
+        BinaryTower::pow(self, exp)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        BinaryTower::to_bytes(self)
+    }
+
+    /// Expects the `max(1, 2^K / 8)` big-endian bytes of a value below `2^(2^K)`.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FieldError> {
+        if bytes.len() != Self::NUM_BYTES {
+            return Err(FieldError::InvalidLength {
+                expected: Self::NUM_BYTES,
+                actual: bytes.len(),
+            });
+        }
+        let value = bytes.iter().fold(0u128, |acc, &b| acc << 8 | b as u128);
+        if value & !Self::MASK != 0 {
+            // Only the sub-byte levels can get here, so the value fits in a u64.
+            return Err(FieldError::NonCanonical {
+                value: value as u64,
+                modulus: (Self::MASK as u64).wrapping_add(1),
+            });
+        }
+        Ok(BinaryTower { value })
+    }
+
+    /// Takes the last `max(1, 2^K / 8)` bytes as the bit pattern of the value.
+    /// The default Horner fold would multiply by `from_u64(256) = 0` and keep
+    /// only the lowest bit.
+    fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
+        let tail = &bytes[bytes.len().saturating_sub(Self::NUM_BYTES)..];
+        BinaryTower::new(tail.iter().fold(0u128, |acc, &b| acc << 8 | b as u128))
+    }
+
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        BinaryTower::random_with(rng)
+    }
+
+    fn characteristic() -> U256 {
+        U256::from(2)
+    }
+
+    fn square(&self) -> Self {
+        BinaryTower::square(self)
+    }
+
+    /// Doubling is always zero in characteristic 2.
+    fn double(&self) -> Self {
+        BinaryTower::zero()
+    }
+}
+
+#[cfg(test)]
+mod test_binary_tower {
+    use super::*;
+    use crate::channel::channel::Channel;
+    use crate::polynomial::Polynomial;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    fn all<const K: u32>() -> impl Iterator<Item = BinaryTower<K>> {
+        (0..1u128 << BinaryTower::<K>::BITS).map(BinaryTower::new)
+    }
+
+    #[test]
+    fn test_small_levels() {
+        // GF(4): X0^2 = X0 + 1.
+        assert_eq!(BinaryField2b::new(2) * BinaryField2b::new(2), BinaryField2b::new(3));
+        // GF(16): X1^2 = X0 X1 + 1, i.e. 0b100^2 = 0b1000 + 1.
+        assert_eq!(BinaryField4b::new(4) * BinaryField4b::new(4), BinaryField4b::new(9));
+        assert_eq!(BinaryField1b::new(1) + BinaryField1b::new(1), BinaryField1b::zero());
+        assert_eq!(BinaryField1b::new(3).value(), 1);
+    }
+
+    #[test]
+    fn test_gf256_is_a_field() {
+        for a in all::<3>() {
+            if a != BinaryField8b::zero() {
+                assert_eq!(a * a.inverse(), BinaryField8b::one());
+                assert_eq!(a.pow(255), BinaryField8b::one());
+            }
+            for b in all::<3>().step_by(7) {
+                assert_eq!(a * b, b * a);
+                for c in all::<3>().step_by(31) {
+                    assert_eq!(a * (b + c), a * b + a * c);
+                    assert_eq!((a * b) * c, a * (b * c));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_large_levels() {
+        let mut rng = ChaCha20Rng::seed_from_u64(128);
+        for _ in 0..50 {
+            let (a, b, c) = (
+                BinaryField128b::random_with(&mut rng),
+                BinaryField128b::random_with(&mut rng),
+                BinaryField128b::random_with(&mut rng),
+            );
+            assert_eq!((a * b) * c, a * (b * c));
+            assert_eq!(a * (b + c), a * b + a * c);
+            assert_eq!(a * a.inverse(), BinaryField128b::one());
+
+            let d = BinaryField32b::random_with(&mut rng);
+            assert_eq!(d.pow(u32::MAX as u64), BinaryField32b::one());
+        }
+    }
+
+    #[test]
+    fn test_subfield_embedding() {
+        let mut rng = ChaCha20Rng::seed_from_u64(8);
+        for _ in 0..50 {
+            let a = BinaryField8b::random_with(&mut rng);
+            let b = BinaryField8b::random_with(&mut rng);
+            let product: BinaryField128b = (a * b).embed();
+            assert_eq!(a.embed::<7>() * b.embed::<7>(), product);
+            assert_eq!(a.embed::<5>().inverse(), a.inverse().embed::<5>());
+        }
+    }
+
+    #[test]
+    fn test_characteristic_two() {
+        let a = BinaryField64b::new(0xdead_beef);
+        assert_eq!(a + a, BinaryField64b::zero());
+        assert_eq!(-a, a);
+        assert_eq!(a - a, BinaryField64b::zero());
+        assert_eq!(<BinaryField64b as Field>::from_u64(3), BinaryField64b::one());
+        assert_eq!(<BinaryField64b as Field>::from_u64(2), BinaryField64b::zero());
+        // The Frobenius map is additive: (a + b)^2 = a^2 + b^2.
+        let b = BinaryField64b::new(12345);
+        assert_eq!((a + b).square(), a.square() + b.square());
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let a = BinaryField128b::new(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10);
+        let bytes = Field::to_bytes(&a);
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(bytes[0], 1);
+        assert_eq!(<BinaryField128b as Field>::from_bytes(&bytes), Ok(a));
+        assert_eq!(Field::to_bytes(&BinaryField2b::new(3)), vec![3]);
+        assert!(<BinaryField2b as Field>::from_bytes(&[4]).is_err());
+        assert!(<BinaryField16b as Field>::from_bytes(&[1]).is_err());
+    }
+
+    #[test]
+    fn test_polynomial_over_tower() {
+        type F = BinaryField32b;
+        let p = Polynomial::new(vec![F::new(7), F::new(0x1234), F::new(1)]);
+        let q = Polynomial::new(vec![F::new(0xabcd), F::one()]);
+        let (quot, rem) = (&p * &q).div_rem(&q);
+        assert_eq!(quot, p);
+        assert!(rem.is_zero());
+        let x = F::new(99);
+        assert_eq!((&p * &q).evaluate(x), p.evaluate(x) * q.evaluate(x));
+    }
+
+    #[test]
+    fn test_clmul_path_matches_recursion() {
+        let mut rng = ChaCha20Rng::seed_from_u64(1313);
+        for level in CLMUL_MIN_LEVEL..=7 {
+            let mask = if level == 7 { u128::MAX } else { (1u128 << (1u32 << level)) - 1 };
+            for _ in 0..200 {
+                let a = BinaryField128b::random_with(&mut rng).value() & mask;
+                let b = BinaryField128b::random_with(&mut rng).value() & mask;
+                assert_eq!(mul_values(a, b, level), tower_mul(a, b, level), "level {level}");
+            }
+            assert_eq!(mul_values(mask, mask, level), tower_mul(mask, mask, level));
+        }
+    }
+
+    #[test]
+    fn test_from_be_bytes_mod_order() {
+        let digest: Vec<u8> = (1..=32).collect();
+        let a = <BinaryField128b as Field>::from_be_bytes_mod_order(&digest);
+        assert_eq!(a.value(), u128::from_be_bytes(digest[16..].try_into().unwrap()));
+        assert_eq!(<BinaryField16b as Field>::from_be_bytes_mod_order(&digest), BinaryField16b::new(0x1f20));
+        assert_eq!(<BinaryField2b as Field>::from_be_bytes_mod_order(&digest), BinaryField2b::new(32));
+        assert_eq!(<BinaryField8b as Field>::from_be_bytes_mod_order(&[]), BinaryField8b::zero());
+    }
+
+    #[test]
+    fn test_channel_challenges_use_the_whole_field() {
+        let mut channel = Channel::<BinaryField128b>::new();
+        let challenges: Vec<BinaryField128b> = (0..8).map(|_| channel.receive_random_field_element()).collect();
+        assert!(challenges.iter().any(|c| c.value() > 1), "challenges confined to {{0, 1}}");
+        assert!(challenges.iter().any(|c| c.value() >> 64 != 0));
+    }
+}
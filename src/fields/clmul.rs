@@ -0,0 +1,107 @@
+//! Carry-less (`GF(2)[x]`) multiplication of 64- and 128-bit words.
+//!
+//! [`clmul64`] uses PCLMULQDQ on x86_64 and PMULL on aarch64 when the CPU has
+//! them, and a shift-and-xor loop everywhere else. Results are identical on
+//! every path.
+
+/// The 127-bit carry-less product of `a` and `b`.
+pub fn clmul64(a: u64, b: u64) -> u128 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("pclmulqdq") {
+            // SAFETY: the CPU supports PCLMULQDQ.
+            return unsafe { x86::clmul64(a, b) };
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if std::arch::is_aarch64_feature_detected!("aes") {
+            // SAFETY: the CPU supports PMULL, which comes with the AES extension.
+            return unsafe { aarch64::clmul64(a, b) };
+        }
+    }
+    clmul64_portable(a, b)
+}
+
+/// [`clmul64`] without any special instructions.
+pub fn clmul64_portable(a: u64, b: u64) -> u128 {
+    let a = a as u128;
+    let mut product = 0u128;
+    for i in 0..64 {
+        // All ones when bit i of b is set.
+        let select = 0u128.wrapping_sub(((b >> i) & 1) as u128);
+        product ^= (a << i) & select;
+    }
+    product
+}
+
+/// The 255-bit carry-less product of `a` and `b` as `(low, high)` halves, from
+/// three [`clmul64`] calls (Karatsuba).
+pub fn clmul128(a: u128, b: u128) -> (u128, u128) {
+    let (a0, a1) = (a as u64, (a >> 64) as u64);
+    let (b0, b1) = (b as u64, (b >> 64) as u64);
+    let z0 = clmul64(a0, b0);
+    let z2 = clmul64(a1, b1);
+    let z1 = clmul64(a0 ^ a1, b0 ^ b1) ^ z0 ^ z2;
+    (z0 ^ (z1 << 64), z2 ^ (z1 >> 64))
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use std::arch::x86_64::*;
+
+    #[target_feature(enable = "pclmulqdq")]
+    pub(super) unsafe fn clmul64(a: u64, b: u64) -> u128 {
+        let product = _mm_clmulepi64_si128(_mm_cvtsi64_si128(a as i64), _mm_cvtsi64_si128(b as i64), 0x00);
+        // Lane 0 is the low half on x86.
+        std::mem::transmute::<__m128i, u128>(product)
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    #[target_feature(enable = "neon,aes")]
+    pub(super) unsafe fn clmul64(a: u64, b: u64) -> u128 {
+        std::arch::aarch64::vmull_p64(a, b)
+    }
+}
+
+#[cfg(test)]
+mod test_clmul {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+
+    #[test]
+    fn test_small_products() {
+        // (x + 1)^2 = x^2 + 1 and (x^2 + x + 1)(x + 1) = x^3 + 1 over GF(2).
+        assert_eq!(clmul64_portable(0b11, 0b11), 0b101);
+        assert_eq!(clmul64_portable(0b111, 0b11), 0b1001);
+        assert_eq!(clmul64_portable(1 << 63, 1 << 63), 1 << 126);
+    }
+
+    #[test]
+    fn test_hardware_matches_portable() {
+        let mut rng = ChaCha20Rng::seed_from_u64(13);
+        for _ in 0..1000 {
+            let (a, b): (u64, u64) = (rng.gen(), rng.gen());
+            assert_eq!(clmul64(a, b), clmul64_portable(a, b));
+        }
+    }
+
+    #[test]
+    fn test_clmul128_matches_schoolbook() {
+        let mut rng = ChaCha20Rng::seed_from_u64(113);
+        for _ in 0..100 {
+            let (a, b): (u128, u128) = (rng.gen(), rng.gen());
+            let (mut lo, mut hi) = (0u128, 0u128);
+            for i in 0..128 {
+                if (b >> i) & 1 == 1 {
+                    lo ^= a << i;
+                    hi ^= if i == 0 { 0 } else { a >> (128 - i) };
+                }
+            }
+            assert_eq!(clmul128(a, b), (lo, hi));
+        }
+    }
+}
@@ -0,0 +1,637 @@
+//! Prime fields whose modulus is chosen at run time.
+//!
+//! A `DynFieldElement` holds its value and an `Arc<FieldContext>` describing
+//! the field: the modulus, its Barrett constant and the group parameters from
+//! [`params`]. Values built from two contexts with different moduli must not
+//! be mixed; the operators and comparisons panic if they are.
+//!
+//! The `Field` trait constructors take no arguments. `zero` and `one` are
+//! context-free values, canonical in every field, that take the context of the
+//! first element they are combined with, so generic code such as `Polynomial`
+//! can build them on any thread. Everything else that needs the modulus
+//! (`from_u64`, `random`, `from_bytes`, `characteristic`, roots of unity) binds
+//! the context from [`FieldContext::scope`] on the calling thread, or else from
+//! the process-wide [`FieldContext::set_default`], when it is called.
+
+use crate::fields::element::mul_hi_u128;
+use crate::fields::{params, Field, FieldElement, FieldError};
+use alloy::primitives::U256;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use rand_core::{OsRng, RngCore};
+use std::cell::RefCell;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::sync::{Arc, RwLock};
+use subtle::ConstantTimeEq;
+
+static DEFAULT_CONTEXT: RwLock<Option<Arc<FieldContext>>> = RwLock::new(None);
+
+thread_local! {
+    static SCOPED_CONTEXT: RefCell<Vec<Arc<FieldContext>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A prime modulus together with the constants its arithmetic needs.
+#[derive(Debug, PartialEq, Eq)]
+pub struct FieldContext {
+    modulus: u64,
+    /// `floor((2^128 - 1) / modulus)`, for Barrett reduction of products.
+    barrett: u128,
+    generator: u64,
+    two_adicity: u32,
+}
+
+impl FieldContext {
+    /// Fails with `FieldError::NotPrime` unless `modulus` is prime.
+    pub fn new(modulus: u64) -> Result<Arc<Self>, FieldError> {
+        let field = params::field_params(modulus)?;
+        Ok(Arc::new(FieldContext {
+            modulus,
+            barrett: u128::MAX / modulus as u128,
+            generator: field.generator,
+            two_adicity: field.two_adicity,
+        }))
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// The smallest generator of the multiplicative group.
+    pub fn generator(&self) -> u64 {
+        self.generator
+    }
+
+    /// The largest `k` with `2^k | modulus - 1`.
+    pub fn two_adicity(&self) -> u32 {
+        self.two_adicity
+    }
+
+    /// `x mod modulus` for `x < modulus^2`. The Barrett quotient is at most
+    /// two below the true one, hence the correction loop.
+    pub fn reduce(&self, x: u128) -> u64 {
+        let q = mul_hi_u128(x, self.barrett);
+        let mut r = x - q * self.modulus as u128;
+        while r >= self.modulus as u128 {
+            r -= self.modulus as u128;
+        }
+        r as u64
+    }
+
+    /// Reduces `value` into this field.
+    pub fn element(self: &Arc<Self>, value: u64) -> DynFieldElement {
+        DynFieldElement::bound(value % self.modulus, Arc::clone(self))
+    }
+
+    pub fn zero(self: &Arc<Self>) -> DynFieldElement {
+        self.element(0)
+    }
+
+    pub fn one(self: &Arc<Self>) -> DynFieldElement {
+        self.element(1)
+    }
+
+    /// Uniform sample by rejection, as in `FieldElement::random_with`.
+    pub fn random_with<R: RngCore + ?Sized>(self: &Arc<Self>, rng: &mut R) -> DynFieldElement {
+        let zone = (1u128 << 64) - (1u128 << 64) % self.modulus as u128;
+        loop {
+            let x = rng.next_u64();
+            if (x as u128) < zone {
+                return self.element(x);
+            }
+        }
+    }
+
+    /// Decodes 8 big-endian bytes holding a value below the modulus.
+    pub fn from_bytes(self: &Arc<Self>, bytes: &[u8]) -> Result<DynFieldElement, FieldError> {
+        let bytes: [u8; 8] = bytes.try_into().map_err(|_| FieldError::InvalidLength {
+            expected: 8,
+            actual: bytes.len(),
+        })?;
+        let value = u64::from_be_bytes(bytes);
+        if value >= self.modulus {
+            return Err(FieldError::NonCanonical {
+                value,
+                modulus: self.modulus,
+            });
+        }
+        Ok(self.element(value))
+    }
+
+    /// Makes `self` the context used by the `Field` queries that need a
+    /// modulus, in every thread that is not inside a [`FieldContext::scope`].
+    pub fn set_default(self: &Arc<Self>) {
+        *DEFAULT_CONTEXT.write().unwrap() = Some(Arc::clone(self));
+    }
+
+    /// Runs `f` with `self` as the context for the `Field` constructors that
+    /// need a modulus on this thread. Scopes nest. Work that `f` hands to other
+    /// threads (e.g. rayon) does not see the scope, but elements built inside
+    /// it carry their context along, and `zero`/`one` need none.
+    pub fn scope<T>(self: &Arc<Self>, f: impl FnOnce() -> T) -> T {
+        struct Guard;
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                SCOPED_CONTEXT.with(|stack| stack.borrow_mut().pop());
+            }
+        }
+
+        SCOPED_CONTEXT.with(|stack| stack.borrow_mut().push(Arc::clone(self)));
+        let _guard = Guard;
+        f()
+    }
+
+    /// The innermost scoped context on this thread, else the default one.
+    pub fn current() -> Option<Arc<Self>> {
+        SCOPED_CONTEXT
+            .with(|stack| stack.borrow().last().cloned())
+            .or_else(|| DEFAULT_CONTEXT.read().unwrap().clone())
+    }
+
+    fn expect_current() -> Arc<Self> {
+        Self::current().expect("no FieldContext in scope: use FieldContext::scope or FieldContext::set_default")
+    }
+}
+
+/// An element of the prime field described by its `FieldContext`, or a
+/// context-free `0` or `1`.
+#[derive(Debug, Clone)]
+pub struct DynFieldElement {
+    repr: Repr,
+}
+
+#[derive(Debug, Clone)]
+enum Repr {
+    /// `value < ctx.modulus`.
+    Bound { value: u64, ctx: Arc<FieldContext> },
+    /// `0` or `1` from `Field::zero`, `one` or `from_u64`, which are canonical
+    /// in every field. Takes the context of the first bound operand it meets.
+    Unbound(u64),
+}
+
+impl DynFieldElement {
+    fn bound(value: u64, ctx: Arc<FieldContext>) -> Self {
+        DynFieldElement { repr: Repr::Bound { value, ctx } }
+    }
+
+    fn unbound(value: u64) -> Self {
+        debug_assert!(value <= 1);
+        DynFieldElement { repr: Repr::Unbound(value) }
+    }
+
+    /// The canonical value.
+    pub fn value(&self) -> u64 {
+        match &self.repr {
+            Repr::Bound { value, .. } | Repr::Unbound(value) => *value,
+        }
+    }
+
+    /// `None` for a context-free `0` or `1`.
+    pub fn modulus(&self) -> Option<u64> {
+        self.context().map(|ctx| ctx.modulus)
+    }
+
+    /// `None` for a context-free `0` or `1`.
+    pub fn context(&self) -> Option<&Arc<FieldContext>> {
+        match &self.repr {
+            Repr::Bound { ctx, .. } => Some(ctx),
+            Repr::Unbound(_) => None,
+        }
+    }
+
+    /// Converts a const-generic element, failing if `M` is not the context's modulus.
+    pub fn from_element<const M: u64>(ctx: &Arc<FieldContext>, element: FieldElement<M>) -> Result<Self, FieldError> {
+        if M != ctx.modulus {
+            return Err(FieldError::ModulusMismatch {
+                expected: ctx.modulus,
+                actual: M,
+            });
+        }
+        Ok(ctx.element(element.value()))
+    }
+
+    /// The context of `self`, else of `other`, else the current one. Only two
+    /// context-free operands whose result is not `0` or `1` get this far
+    /// without a context of their own.
+    fn context_with(&self, other: &Self) -> Arc<FieldContext> {
+        match (&self.repr, &other.repr) {
+            (Repr::Bound { ctx, .. }, _) | (_, Repr::Bound { ctx, .. }) => Arc::clone(ctx),
+            _ => FieldContext::expect_current(),
+        }
+    }
+
+    /// Panics unless both operands may be combined: they share a modulus, or
+    /// one of them is context-free.
+    fn check_same_field(&self, other: &Self) {
+        if let (Repr::Bound { ctx: a, .. }, Repr::Bound { ctx: b, .. }) = (&self.repr, &other.repr) {
+            assert!(
+                Arc::ptr_eq(a, b) || a.modulus == b.modulus,
+                "modulus mismatch: {} and {}",
+                a.modulus,
+                b.modulus
+            );
+        }
+    }
+
+    /// `unbound` on the values of two context-free operands when the result is
+    /// `0` or `1`, otherwise `field` in the shared context.
+    fn combine(self, rhs: Self, unbound: fn(i128, i128) -> i128, field: fn(&FieldContext, u64, u64) -> u64) -> Self {
+        self.check_same_field(&rhs);
+        if let (Repr::Unbound(a), Repr::Unbound(b)) = (&self.repr, &rhs.repr) {
+            let c = unbound(*a as i128, *b as i128);
+            if c == 0 || c == 1 {
+                return Self::unbound(c as u64);
+            }
+        }
+        let ctx = self.context_with(&rhs);
+        let value = field(&ctx, self.value(), rhs.value());
+        Self::bound(value, ctx)
+    }
+
+    pub fn pow(&self, mut exp: u64) -> Self {
+        let (mut base, ctx) = match &self.repr {
+            Repr::Bound { value, ctx } => (*value, ctx),
+            // 0^0 = 1, and 0 and 1 are fixed by every other power.
+            Repr::Unbound(value) => return Self::unbound(if exp == 0 { 1 } else { *value }),
+        };
+        let mut result = 1 % ctx.modulus;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = ctx.reduce(result as u128 * base as u128);
+            }
+            base = ctx.reduce(base as u128 * base as u128);
+            exp >>= 1;
+        }
+        Self::bound(result, Arc::clone(ctx))
+    }
+
+    /// Fermat inverse, `self^(p-2)`. Zero maps to zero.
+    pub fn inverse(&self) -> Self {
+        match &self.repr {
+            Repr::Bound { ctx, .. } => self.pow(ctx.modulus - 2),
+            Repr::Unbound(_) => self.clone(),
+        }
+    }
+
+    /// 8 big-endian bytes, like `FieldElement::to_bytes`.
+    pub fn to_bytes(&self) -> [u8; 8] {
+        self.value().to_be_bytes()
+    }
+}
+
+impl<const M: u64> TryFrom<DynFieldElement> for FieldElement<M> {
+    type Error = FieldError;
+
+    fn try_from(element: DynFieldElement) -> Result<Self, Self::Error> {
+        match element.modulus() {
+            Some(modulus) if modulus != M => Err(FieldError::ModulusMismatch {
+                expected: M,
+                actual: modulus,
+            }),
+            _ => Ok(FieldElement::new(element.value())),
+        }
+    }
+}
+
+/// Compares values. Like the operators, comparing elements of two different
+/// fields panics; a context-free `0` or `1` equals the `0` or `1` of any field.
+impl PartialEq for DynFieldElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.check_same_field(other);
+        self.value().ct_eq(&other.value()).into()
+    }
+}
+
+impl Eq for DynFieldElement {}
+
+/// Hashes the value alone, which is consistent with `PartialEq`.
+impl Hash for DynFieldElement {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value().hash(state);
+    }
+}
+
+impl Add for DynFieldElement {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, |a, b| a + b, |ctx, a, b| {
+            let sum = a as u128 + b as u128;
+            let modulus = ctx.modulus as u128;
+            (if sum >= modulus { sum - modulus } else { sum }) as u64
+        })
+    }
+}
+
+impl AddAssign for DynFieldElement {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl Sub for DynFieldElement {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, |a, b| a - b, |ctx, a, b| {
+            if a >= b {
+                a - b
+            } else {
+                (a as u128 + ctx.modulus as u128 - b as u128) as u64
+            }
+        })
+    }
+}
+
+impl SubAssign for DynFieldElement {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl Mul for DynFieldElement {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.combine(rhs, |a, b| a * b, |ctx, a, b| ctx.reduce(a as u128 * b as u128))
+    }
+}
+
+impl MulAssign for DynFieldElement {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl Div for DynFieldElement {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+impl DivAssign for DynFieldElement {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs.inverse();
+    }
+}
+
+impl Neg for DynFieldElement {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::unbound(0) - self
+    }
+}
+
+impl fmt::Display for DynFieldElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value(), f)
+    }
+}
+
+/// Samples from the current context; panics if there is none.
+impl Distribution<DynFieldElement> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DynFieldElement {
+        FieldContext::expect_current().random_with(rng)
+    }
+}
+
+/// `zero`, `one` (and `from_u64` of `0` or `1`) are context-free, so generic
+/// code can build them on any thread, including rayon workers. Every other
+/// constructor binds [`FieldContext::current`] when it is called and panics if
+/// there is none; the root-of-unity and generator queries report `None`
+/// (two-adicity `0`) instead, so algorithms fall back to paths that do not
+/// need them.
+impl Field for DynFieldElement {
+    fn zero() -> Self {
+        Self::unbound(0)
+    }
+
+    fn one() -> Self {
+        Self::unbound(1)
+    }
+
+    fn from_u64(value: u64) -> Self {
+        if value <= 1 {
+            Self::unbound(value)
+        } else {
+            FieldContext::expect_current().element(value)
+        }
+    }
+
+    fn inverse(&self) -> Self {
+        DynFieldElement::inverse(self)
+    }
+
+    fn pow(&self, exp: u64) -> Self {
+        DynFieldElement::pow(self, exp)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        DynFieldElement::to_bytes(self).to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FieldError> {
+        FieldContext::expect_current().from_bytes(bytes)
+    }
+
+    fn random() -> Self {
+        FieldContext::expect_current().random_with(&mut OsRng)
+    }
+
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        FieldContext::expect_current().random_with(rng)
+    }
+
+    fn characteristic() -> U256 {
+        U256::from(FieldContext::expect_current().modulus)
+    }
+
+    fn two_adicity() -> u32 {
+        FieldContext::current().map_or(0, |ctx| ctx.two_adicity)
+    }
+
+    fn two_adic_root_of_unity(log_n: u32) -> Option<Self> {
+        let ctx = FieldContext::current()?;
+        params::two_adic_root_of_unity(ctx.modulus, log_n).map(|root| ctx.element(root))
+    }
+
+    fn multiplicative_generator() -> Option<Self> {
+        let ctx = FieldContext::current()?;
+        Some(ctx.element(ctx.generator))
+    }
+}
+
+#[cfg(test)]
+mod test_dynamic {
+    use super::*;
+    use crate::fields::field::BATCH_INVERSE_CHUNK;
+    use crate::fields::Goldilocks;
+    use crate::polynomial::{mul, tree, Polynomial, SubproductTree};
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+    use std::collections::HashSet;
+
+    const BABY_BEAR: u64 = 3221225473;
+    const GOLDILOCKS: u64 = Goldilocks::MODULUS;
+
+    #[test]
+    fn test_matches_const_generic() {
+        let mut rng = ChaCha20Rng::seed_from_u64(16);
+        for modulus in [BABY_BEAR, GOLDILOCKS] {
+            let ctx = FieldContext::new(modulus).unwrap();
+            for _ in 0..200 {
+                let (a, b) = (ctx.random_with(&mut rng), ctx.random_with(&mut rng));
+                let (x, y) = (a.value() as u128, b.value() as u128);
+                let m = modulus as u128;
+                assert_eq!((a.clone() + b.clone()).value() as u128, (x + y) % m);
+                assert_eq!((a.clone() - b.clone()).value() as u128, (x + m - y) % m);
+                assert_eq!((a.clone() * b.clone()).value() as u128, x * y % m);
+                assert_eq!((-a.clone()).value() as u128, (m - x) % m);
+                if a.value() != 0 {
+                    assert_eq!(a.clone() * a.inverse(), ctx.one());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_barrett_edges() {
+        let ctx = FieldContext::new(GOLDILOCKS).unwrap();
+        let top = (GOLDILOCKS - 1) as u128;
+        assert_eq!(ctx.reduce(top * top), 1);
+        assert_eq!(ctx.reduce(GOLDILOCKS as u128), 0);
+        assert_eq!(ctx.reduce(top), GOLDILOCKS - 1);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let ctx = FieldContext::new(BABY_BEAR).unwrap();
+        let fe = FieldElement::<BABY_BEAR>::new(123456789);
+        let dynamic = DynFieldElement::from_element(&ctx, fe).unwrap();
+        assert_eq!(dynamic.value(), 123456789);
+        assert_eq!(FieldElement::<BABY_BEAR>::try_from(dynamic.clone()), Ok(fe));
+        assert_eq!(
+            FieldElement::<17>::try_from(dynamic),
+            Err(FieldError::ModulusMismatch {
+                expected: 17,
+                actual: BABY_BEAR
+            })
+        );
+        assert!(DynFieldElement::from_element(&ctx, FieldElement::<17>::new(1)).is_err());
+        assert_eq!(FieldContext::new(15).unwrap_err(), FieldError::NotPrime(15));
+        assert_eq!(
+            ctx.from_bytes(&BABY_BEAR.to_be_bytes()).unwrap_err(),
+            FieldError::NonCanonical {
+                value: BABY_BEAR,
+                modulus: BABY_BEAR
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus mismatch")]
+    fn test_mixing_moduli_panics() {
+        let a = FieldContext::new(BABY_BEAR).unwrap().one();
+        let b = FieldContext::new(GOLDILOCKS).unwrap().one();
+        let _ = a + b;
+    }
+
+    #[test]
+    fn test_parallel_work_in_scope() {
+        // These paths build `zero()` and `one()` on rayon workers, which never
+        // see the scope. No test installs a default.
+        let ctx = FieldContext::new(BABY_BEAR).unwrap();
+        let mut rng = ChaCha20Rng::seed_from_u64(116);
+        let to_static = |xs: &[u64]| xs.iter().map(|&x| FieldElement::<BABY_BEAR>::new(x)).collect::<Vec<_>>();
+        let values: Vec<u64> = (0..3 * BATCH_INVERSE_CHUNK).map(|_| rng.gen_range(0..BABY_BEAR)).collect();
+        let mut expected_inverses = to_static(&values);
+        FieldElement::batch_inverse(&mut expected_inverses);
+        let a: Vec<u64> = (0..1500).map(|_| rng.gen_range(0..BABY_BEAR)).collect();
+        let b: Vec<u64> = (0..1200).map(|_| rng.gen_range(0..BABY_BEAR)).collect();
+        let expected: Vec<u64> = mul::karatsuba(&to_static(&a), &to_static(&b)).iter().map(|c| c.value()).collect();
+        let points: Vec<u64> = (1..=1100).collect();
+        let expected_values: Vec<u64> = tree::evaluate_many(
+            &Polynomial::new(to_static(&a)),
+            &SubproductTree::new(&to_static(&points)),
+        )
+        .iter()
+        .map(|v| v.value())
+        .collect();
+
+        ctx.scope(|| {
+            let to_dyn = |xs: &[u64]| xs.iter().map(|&x| ctx.element(x)).collect::<Vec<_>>();
+            let mut inverses = to_dyn(&values);
+            DynFieldElement::batch_inverse_parallel(&mut inverses);
+            assert!(inverses.iter().zip(&expected_inverses).all(|(x, y)| x.value() == y.value()));
+
+            let product: Vec<u64> = mul::karatsuba(&to_dyn(&a), &to_dyn(&b)).iter().map(|c| c.value()).collect();
+            assert_eq!(product, expected);
+
+            let p = Polynomial::new(to_dyn(&a));
+            let values = tree::evaluate_many(&p, &SubproductTree::new(&to_dyn(&points)));
+            assert_eq!(values.iter().map(|v| v.value()).collect::<Vec<_>>(), expected_values);
+            assert_eq!(p.evaluate(ctx.element(7)).value(), expected_values[6]);
+        });
+    }
+
+    #[test]
+    fn test_zero_and_one_adopt_context() {
+        let ctx = FieldContext::new(GOLDILOCKS).unwrap();
+        let one = DynFieldElement::one();
+        assert_eq!(one.context(), None);
+        assert_eq!(one.to_bytes(), 1u64.to_be_bytes());
+        assert_eq!(one.inverse(), one);
+        assert_eq!(DynFieldElement::zero().pow(0), one);
+        assert_eq!(DynFieldElement::from_u64(0), DynFieldElement::zero());
+        assert_eq!(one.clone() * one.clone() - DynFieldElement::zero(), one);
+
+        let minus_one = DynFieldElement::zero() - ctx.one();
+        assert_eq!(minus_one.value(), GOLDILOCKS - 1);
+        assert_eq!((one.clone() + one.clone() * ctx.element(5)).context(), Some(&ctx));
+        assert_eq!(one, ctx.one());
+        assert_eq!(-ctx.one(), minus_one);
+        assert_eq!(FieldElement::<17>::try_from(one.clone()), Ok(FieldElement::one()));
+        assert_eq!(DynFieldElement::two_adic_root_of_unity(1), None);
+
+        // Equal elements hash alike, with or without a context.
+        let set: HashSet<DynFieldElement> = [one, ctx.one(), DynFieldElement::zero(), ctx.zero()].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_scope_binds_at_construction() {
+        let ctx = FieldContext::new(BABY_BEAR).unwrap();
+        let two = ctx.scope(|| DynFieldElement::from_u64(2));
+        assert_eq!(two.context(), Some(&ctx));
+        // Built in the scope, used outside it and on a rayon worker.
+        let (inverse, square) = rayon::join(|| two.inverse(), || two.pow(2));
+        assert_eq!(inverse * two.clone(), DynFieldElement::one());
+        assert_eq!(square.value(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "no FieldContext in scope")]
+    fn test_from_u64_needs_context() {
+        let _ = DynFieldElement::from_u64(2);
+    }
+
+    #[test]
+    fn test_scope_overrides_default() {
+        let ctx = FieldContext::new(GOLDILOCKS).unwrap();
+        ctx.scope(|| {
+            assert_eq!(DynFieldElement::characteristic(), U256::from(GOLDILOCKS));
+            assert_eq!(DynFieldElement::from_u64(7), ctx.element(7));
+
+            let root = DynFieldElement::two_adic_root_of_unity(32).unwrap();
+            assert_eq!(root.pow(1 << 32), ctx.one());
+            assert_ne!(root.pow(1 << 31), ctx.one());
+            assert_eq!(DynFieldElement::two_adic_root_of_unity(33), None);
+        });
+    }
+}
@@ -1,21 +1,57 @@
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
 use rand_core::{RngCore, OsRng};
-use subtle::ConstantTimeEq;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use alloy::primitives::U256;
+use crate::fields::{packed, params, Field, FieldError};
+use std::fmt;
+use std::str::FromStr;
 
 
 /// An element of the given Field.
-#[derive(Debug, Clone, Copy,Hash)]
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
 pub struct FieldElement<const MODULUS: u64> {
     value: u64,
 }
 
+/// High 128 bits of the 256-bit product `a * b`.
+#[inline(always)]
+pub(crate) fn mul_hi_u128(a: u128, b: u128) -> u128 {
+    const LO: u128 = u64::MAX as u128;
+    let (a0, a1) = (a & LO, a >> 64);
+    let (b0, b1) = (b & LO, b >> 64);
+    let (lo_lo, lo_hi, hi_lo, hi_hi) = (a0 * b0, a0 * b1, a1 * b0, a1 * b1);
+    let middle = (lo_lo >> 64) + (lo_hi & LO) + (hi_lo & LO);
+    hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (middle >> 64)
+}
+
 impl<const MODULUS: u64> FieldElement<MODULUS> {
+    /// `floor((2^128 - 1) / MODULUS)`, for Barrett reduction.
+    const BARRETT: u128 = u128::MAX / MODULUS as u128;
+
     pub fn new(value: u64) -> Self {
         FieldElement {
-            value: value % MODULUS,
+            value: Self::reduce(value as u128),
         }
     }
 
+    /// `x mod MODULUS` by Barrett reduction, using only multiplications and a
+    /// branch-free correction, so the time does not depend on `x`.
+    ///
+    /// The estimated quotient `floor(x * BARRETT / 2^128)` is at most one below
+    /// the true one, so the remainder `x - q * MODULUS` is below `2 * MODULUS`.
+    #[inline(always)]
+    fn reduce(x: u128) -> u64 {
+        let modulus = MODULUS as u128;
+        let r = x - mul_hi_u128(x, Self::BARRETT) * modulus;
+        let (reduced, borrow) = r.overflowing_sub(modulus);
+        // Adds MODULUS back on borrow without a branch.
+        reduced.wrapping_add(modulus & 0u128.wrapping_sub(borrow as u128)) as u64
+    }
+
     pub fn zero() -> Self {
         FieldElement { value: 0 }
     }
@@ -29,25 +65,45 @@ impl<const MODULUS: u64> FieldElement<MODULUS> {
     }
 
     pub fn random() -> Self {
-        let mut rng = OsRng;
-        let value = rng.next_u64() % MODULUS;
-        FieldElement::new(value)
+        Self::random_with(&mut OsRng)
+    }
+
+    /// Uniform sample by rejection: `next_u64() % MODULUS` alone would favour
+    /// small values, so draws at or above the largest multiple of `MODULUS`
+    /// below `2^64` are thrown away.
+    pub fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        let modulus = MODULUS as u128;
+        let zone = (1u128 << 64) - (1u128 << 64) % modulus;
+        loop {
+            let x = rng.next_u64();
+            if (x as u128) < zone {
+                return FieldElement { value: Self::reduce(x as u128) };
+            }
+        }
     }
 
-    /// Modular exponentiation using a constant-time algorithm.
+    /// Modular exponentiation with a Montgomery ladder.
+    ///
+    /// Every one of the 64 exponent bits costs one multiplication and one
+    /// squaring, and the bit only drives a constant-time swap, so neither the
+    /// running time nor the memory access pattern depends on `exp`.
     pub fn pow(&self, exp: u64) -> Self {
-        let mut result = 1u64;
-        let mut base = self.value;
-        let mut e = exp;
-
-        while e > 0 {
-            if e & 1 == 1 {
-                result = (result * base) % MODULUS;
-            }
-            base = (base * base) % MODULUS;
-            e >>= 1;
+        let mut r0 = FieldElement::new(1);
+        let mut r1 = *self;
+        for i in (0..64).rev() {
+            let bit = Choice::from(((exp >> i) & 1) as u8);
+            // bit = 0: (r0, r1) <- (r0^2, r0 * r1); bit = 1: (r0, r1) <- (r0 * r1, r1^2)
+            Self::conditional_swap(&mut r0, &mut r1, bit);
+            r1 = r0 * r1;
+            r0 = r0 * r0;
+            Self::conditional_swap(&mut r0, &mut r1, bit);
         }
-        FieldElement { value: result }
+        r0
+    }
+
+    /// `a^(p-2)`, or none for zero. Runs in the same time for every input.
+    pub fn ct_inverse(&self) -> CtOption<Self> {
+        CtOption::new(self.pow(MODULUS - 2), !self.value.ct_eq(&0))
     }
 
     /// `a^(p-2) % p`.
@@ -59,27 +115,152 @@ impl<const MODULUS: u64> FieldElement<MODULUS> {
     pub fn to_bytes(&self) -> [u8; 8] {
         self.value.to_be_bytes() //big endian
     }
+
+    pub fn to_bytes_le(&self) -> [u8; 8] {
+        self.value.to_le_bytes()
+    }
+
+    /// Parses 8 big-endian bytes, the encoding of [`FieldElement::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FieldError> {
+        Self::from_canonical_u64(u64::from_be_bytes(Self::byte_array(bytes)?))
+    }
+
+    /// Parses 8 little-endian bytes, the encoding of [`FieldElement::to_bytes_le`].
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<Self, FieldError> {
+        Self::from_canonical_u64(u64::from_le_bytes(Self::byte_array(bytes)?))
+    }
+
+    /// Wraps `value` without reducing it; fails unless `value < MODULUS`.
+    pub fn from_canonical_u64(value: u64) -> Result<Self, FieldError> {
+        if value >= MODULUS {
+            return Err(FieldError::NonCanonical { value, modulus: MODULUS });
+        }
+        Ok(FieldElement { value })
+    }
+
+    fn byte_array(bytes: &[u8]) -> Result<[u8; 8], FieldError> {
+        bytes.try_into().map_err(|_| FieldError::InvalidLength {
+            expected: 8,
+            actual: bytes.len(),
+        })
+    }
+
+    /// Legendre symbol `(a / p)`: `1` for a non-zero square, `-1` for a
+    /// non-square and `0` for zero (Euler's criterion).
+    pub fn legendre(&self) -> i8 {
+        if self.value == 0 {
+            return 0;
+        }
+        if self.pow((MODULUS - 1) / 2).value == 1 {
+            1
+        } else {
+            -1
+        }
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.legendre() >= 0
+    }
+
+    /// A square root of `self`, or `None` if it is not a square.
+    ///
+    /// Of the two roots `r` and `-r` the one with the smaller canonical value is
+    /// returned, so the result is deterministic.
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.value == 0 || MODULUS == 2 {
+            return Some(*self);
+        }
+        if self.legendre() != 1 {
+            return None;
+        }
+        let root = if MODULUS % 4 == 3 {
+            // a^((p+1)/4) squares to a * a^((p-1)/2) = a.
+            self.pow((MODULUS >> 2) + 1)
+        } else {
+            self.tonelli_shanks()
+        };
+        let other = -root;
+        Some(if root.value <= other.value { root } else { other })
+    }
+
+    /// Tonelli–Shanks for a known non-zero square, with `p - 1 = q * 2^s`.
+    fn tonelli_shanks(&self) -> Self {
+        let s = (MODULUS - 1).trailing_zeros();
+        let q = (MODULUS - 1) >> s;
+        let z = (2..MODULUS)
+            .map(FieldElement::new)
+            .find(|z| z.legendre() == -1)
+            .expect("an odd prime field has a quadratic non-residue");
+
+        let mut m = s;
+        let mut c = z.pow(q);
+        let mut t = self.pow(q);
+        let mut r = self.pow(q.div_ceil(2));
+        // Invariant: r^2 = a * t, and t has order dividing 2^(m-1).
+        while t.value != 1 {
+            let mut i = 0;
+            let mut t_pow = t;
+            while t_pow.value != 1 {
+                t_pow = t_pow * t_pow;
+                i += 1;
+            }
+            let b = c.pow(1 << (m - i - 1));
+            m = i;
+            c = b * b;
+            t *= c;
+            r *= b;
+        }
+        r
+    }
 }
 
 impl<const MODULUS: u64> PartialEq for FieldElement<MODULUS> {
     fn eq(&self, other: &Self) -> bool {
-        self.value.ct_eq(&other.value).unwrap_u8() == 1
+        self.ct_eq(other).unwrap_u8() == 1
+    }
+}
+
+impl<const MODULUS: u64> ConstantTimeEq for FieldElement<MODULUS> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.value.ct_eq(&other.value)
+    }
+}
+
+impl<const MODULUS: u64> ConditionallySelectable for FieldElement<MODULUS> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        FieldElement {
+            value: u64::conditional_select(&a.value, &b.value, choice),
+        }
     }
 }
 
 impl<const MODULUS: u64> Eq for FieldElement<MODULUS> {}
 
+impl<const MODULUS: u64> Hash for FieldElement<MODULUS> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
 impl<const MODULUS: u64> Add for FieldElement<MODULUS> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        FieldElement::new(self.value + rhs.value)
+        // Both operands are < MODULUS, so one conditional subtraction suffices;
+        // the carry covers moduli above 2^63 where the sum wraps u64. The
+        // subtraction is always computed and the result picked without branching.
+        let (sum, carry) = self.value.overflowing_add(rhs.value);
+        let (reduced, borrow) = sum.overflowing_sub(MODULUS);
+        let keep_reduced = Choice::from(carry as u8 | !borrow as u8);
+        FieldElement {
+            value: u64::conditional_select(&sum, &reduced, keep_reduced),
+        }
     }
 }
 
 impl<const MODULUS: u64> AddAssign for FieldElement<MODULUS> {
     fn add_assign(&mut self, rhs: Self) {
-        self.value = (self.value + rhs.value) % MODULUS;
+        *self = *self + rhs;
     }
 }
 
@@ -87,43 +268,52 @@ impl<const MODULUS: u64> Sub for FieldElement<MODULUS> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let diff = MODULUS + self.value - rhs.value;
-        FieldElement::new(diff % MODULUS)
+        let (diff, borrow) = self.value.overflowing_sub(rhs.value);
+        // Adds MODULUS back on borrow without a branch.
+        let correction = u64::conditional_select(&0, &MODULUS, Choice::from(borrow as u8));
+        FieldElement {
+            value: diff.wrapping_add(correction),
+        }
     }
 }
 
 impl<const MODULUS: u64> SubAssign for FieldElement<MODULUS> {
     fn sub_assign(&mut self, rhs: Self) {
-        let diff = MODULUS + self.value - rhs.value;
-        self.value = diff % MODULUS;
+        *self = *self - rhs;
     }
 }
 
 impl<const MODULUS: u64> Mul for FieldElement<MODULUS> {
     type Output = Self;
 
+    /// The product is reduced by Barrett reduction rather than `u128 %`, whose
+    /// 128-bit division routine takes data-dependent time.
     fn mul(self, rhs: Self) -> Self::Output {
-        FieldElement::new((self.value as u128 * rhs.value as u128 % MODULUS as u128) as u64)
+        FieldElement {
+            value: Self::reduce(self.value as u128 * rhs.value as u128),
+        }
     }
 }
 
 impl<const MODULUS: u64> MulAssign for FieldElement<MODULUS> {
     fn mul_assign(&mut self, rhs: Self) {
-        self.value = (self.value as u128 * rhs.value as u128 % MODULUS as u128) as u64;
+        self.value = Self::reduce(self.value as u128 * rhs.value as u128);
     }
 }
 
 impl<const MODULUS: u64> Div for FieldElement<MODULUS> {
     type Output = Self;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn div(self, rhs: Self) -> Self::Output {
         self * rhs.inverse()
     }
 }
 
 impl<const MODULUS: u64> DivAssign for FieldElement<MODULUS> {
+    #[allow(clippy::suspicious_op_assign_impl)]
     fn div_assign(&mut self, rhs: Self) {
-        *self = *self * rhs.inverse();
+        *self *= rhs.inverse();
     }
 }
 
@@ -131,7 +321,17 @@ impl<const MODULUS: u64> Neg for FieldElement<MODULUS> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        FieldElement::new(MODULUS - self.value)
+        FieldElement::zero() - self
+    }
+}
+
+/// Needed for `subtle::ConditionallyNegatable`, which is implemented for every
+/// `ConditionallySelectable` type whose references can be negated.
+impl<const MODULUS: u64> Neg for &FieldElement<MODULUS> {
+    type Output = FieldElement<MODULUS>;
+
+    fn neg(self) -> Self::Output {
+        -*self
     }
 }
 
@@ -146,6 +346,112 @@ impl<const M: u64> From<i128> for FieldElement<M> {
     }
 }
 
+impl<const MODULUS: u64> fmt::Display for FieldElement<MODULUS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl<const MODULUS: u64> fmt::LowerHex for FieldElement<MODULUS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.value, f)
+    }
+}
+
+impl<const MODULUS: u64> fmt::UpperHex for FieldElement<MODULUS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.value, f)
+    }
+}
+
+/// Parses a canonical decimal value, or hex with a `0x` prefix.
+impl<const MODULUS: u64> FromStr for FieldElement<MODULUS> {
+    type Err = FieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let value = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) => u64::from_str_radix(hex, 16),
+            None => s.parse::<u64>(),
+        }
+        .map_err(|_| FieldError::InvalidLiteral(s.to_string()))?;
+        Self::from_canonical_u64(value)
+    }
+}
+
+impl<const M: u64> Distribution<FieldElement<M>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FieldElement<M> {
+        FieldElement::random_with(rng)
+    }
+}
+
+impl<const M: u64> Field for FieldElement<M> {
+    fn zero() -> Self {
+        FieldElement::zero()
+    }
+
+    fn one() -> Self {
+        FieldElement::one()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        FieldElement::new(value)
+    }
+
+    fn inverse(&self) -> Self {
+        FieldElement::inverse(self)
+    }
+
+    fn pow(&self, exp: u64) -> Self {
+        FieldElement::pow(self, exp)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        FieldElement::to_bytes(self).to_vec()
+    }
+
+    /// Expects exactly 8 big-endian bytes holding a value below `M`.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FieldError> {
+        FieldElement::from_bytes(bytes)
+    }
+
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        FieldElement::random_with(rng)
+    }
+
+    fn characteristic() -> U256 {
+        U256::from(M)
+    }
+
+    fn two_adicity() -> u32 {
+        (M - 1).trailing_zeros()
+    }
+
+    fn two_adic_root_of_unity(log_n: u32) -> Option<Self> {
+        params::two_adic_root_of_unity(M, log_n).map(FieldElement::new)
+    }
+
+    fn multiplicative_generator() -> Option<Self> {
+        FieldElement::generator().ok()
+    }
+
+    fn add_slices(lhs: &mut [Self], rhs: &[Self]) {
+        packed::add_assign_slices(lhs, rhs);
+    }
+
+    fn sub_slices(lhs: &mut [Self], rhs: &[Self]) {
+        packed::sub_assign_slices(lhs, rhs);
+    }
+
+    fn mul_slices(lhs: &mut [Self], rhs: &[Self]) {
+        packed::mul_assign_slices(lhs, rhs);
+    }
+
+    fn scale_slice(values: &mut [Self], scalar: &Self) {
+        packed::scale_slice(values, *scalar);
+    }
+}
+
 #[cfg(test)]
 mod test_field_operations {
     use super::*;
@@ -281,6 +587,53 @@ mod test_field_operations {
         assert_eq!(result.value(), 3);
     }
 
+    #[test]
+    fn test_field_trait_bytes_round_trip() {
+        let a = FieldElement::<7>::new(5);
+        let bytes = Field::to_bytes(&a);
+        assert_eq!(<FieldElement<7> as Field>::from_bytes(&bytes), Ok(a));
+        assert!(<FieldElement<7> as Field>::from_bytes(&9u64.to_be_bytes()).is_err());
+        assert!(<FieldElement<7> as Field>::from_bytes(&[1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_field_trait_from_be_bytes_mod_order() {
+        // 0x0102 = 258 = 6 mod 7
+        let a = <FieldElement<7> as Field>::from_be_bytes_mod_order(&[1, 2]);
+        assert_eq!(a.value(), 6);
+    }
+
+    #[test]
+    fn test_arithmetic_near_u64_max() {
+        // Largest 64-bit prime: sums and differences wrap u64.
+        const P: u64 = 18446744073709551557;
+        let a = FieldElement::<P>::new(P - 1);
+        let b = FieldElement::<P>::new(P - 2);
+        assert_eq!((a + b).value(), P - 3);
+        assert_eq!((b - a).value(), P - 1);
+        assert_eq!((a - b).value(), 1);
+        let mut c = a;
+        c += b;
+        assert_eq!(c.value(), P - 3);
+        c -= a;
+        assert_eq!(c, b);
+        assert_eq!((-a).value(), 1);
+        // (-1)^2 = 1, (-1)^3 = -1
+        assert_eq!(a.pow(2).value(), 1);
+        assert_eq!(a.pow(3), a);
+        assert_eq!((b * b.inverse()).value(), 1);
+    }
+
+    #[test]
+    fn test_pow_above_2_32() {
+        // Intermediate squares exceed 2^32, so the old u64 products overflowed.
+        const P: u64 = 0xFFFF_FFFF_0000_0001;
+        let a = FieldElement::<P>::new(0xFFFF_FFFF);
+        let expected = (0xFFFF_FFFFu128 * 0xFFFF_FFFF % P as u128 * 0xFFFF_FFFF % P as u128) as u64;
+        assert_eq!(a.pow(3).value(), expected);
+        assert_eq!(a.pow(P - 1).value(), 1);
+    }
+
     #[test]
     fn test_inverse_multiplication() {
         let a = FieldElement::<7>::new(3);
@@ -288,3 +641,278 @@ mod test_field_operations {
         assert_eq!((a * inv).value(), 1);
     }
 }
+
+#[cfg(test)]
+mod test_sqrt {
+    use super::*;
+
+    fn check_all_squares<const M: u64>() {
+        let mut squares = 0;
+        for v in 0..M {
+            let a = FieldElement::<M>::new(v);
+            match a.sqrt() {
+                Some(r) => {
+                    assert_eq!(r * r, a);
+                    assert!(r.value() <= (-r).value());
+                    assert!(a.is_square());
+                    squares += 1;
+                }
+                None => assert_eq!(a.legendre(), -1),
+            }
+        }
+        // Zero plus the (p-1)/2 non-zero squares.
+        assert_eq!(squares, (M - 1) / 2 + 1);
+    }
+
+    fn check_random_squares<const M: u64>() {
+        for v in [2u64, 3, 5, 12345, 0x1234_5678, M - 1, M - 2] {
+            let a = FieldElement::<M>::new(v);
+            let sq = a * a;
+            let r = sq.sqrt().expect("a square has a root");
+            assert_eq!(r * r, sq);
+            assert!(r == a || r == -a);
+            assert_eq!(sq.legendre(), 1);
+        }
+    }
+
+    #[test]
+    fn test_small_primes_exhaustive() {
+        check_all_squares::<7>(); // 3 mod 4
+        check_all_squares::<13>(); // 1 mod 4, s = 2
+        check_all_squares::<17>(); // 1 mod 4, s = 4
+        check_all_squares::<97>(); // s = 5
+    }
+
+    #[test]
+    fn test_bench_primes() {
+        check_random_squares::<2147483647>(); // Mersenne31, 3 mod 4
+        check_random_squares::<0x7800_0001>(); // BabyBear, s = 27
+        check_random_squares::<0x7f00_0001>(); // KoalaBear, s = 24
+        check_random_squares::<0xFFFF_FFFF_0000_0001>(); // Goldilocks, s = 32
+    }
+
+    #[test]
+    fn test_non_squares() {
+        // 3 is a non-residue mod 7, and a generator is never a square.
+        assert_eq!(FieldElement::<7>::new(3).sqrt(), None);
+        assert!(!FieldElement::<7>::new(3).is_square());
+        let g = FieldElement::<0xFFFF_FFFF_0000_0001>::new(7);
+        assert_eq!(g.legendre(), -1);
+        assert_eq!(g.sqrt(), None);
+        assert_eq!(FieldElement::<17>::zero().legendre(), 0);
+        assert_eq!(FieldElement::<17>::zero().sqrt(), Some(FieldElement::zero()));
+    }
+}
+
+#[cfg(test)]
+mod test_encoding {
+    use super::*;
+
+    const P: u64 = 0xFFFF_FFFF_0000_0001;
+    type F = FieldElement<P>;
+
+    #[test]
+    fn test_bytes_round_trip_both_endiannesses() {
+        let a = F::new(0x0102_0304_0506_0708);
+        assert_eq!(a.to_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(a.to_bytes_le(), [8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(F::from_bytes(&a.to_bytes()), Ok(a));
+        assert_eq!(F::from_bytes_le(&a.to_bytes_le()), Ok(a));
+    }
+
+    #[test]
+    fn test_rejects_non_canonical_and_bad_length() {
+        assert_eq!(
+            F::from_bytes(&P.to_be_bytes()),
+            Err(FieldError::NonCanonical { value: P, modulus: P })
+        );
+        assert!(F::from_bytes_le(&u64::MAX.to_le_bytes()).is_err());
+        assert_eq!(
+            F::from_bytes(&[0; 9]),
+            Err(FieldError::InvalidLength { expected: 8, actual: 9 })
+        );
+        assert_eq!(F::from_bytes(&(P - 1).to_be_bytes()), Ok(F::new(P - 1)));
+    }
+
+    #[test]
+    fn test_display_and_hex() {
+        let a = FieldElement::<17>::new(12);
+        assert_eq!(a.to_string(), "12");
+        assert_eq!(format!("{:x}", a), "c");
+        assert_eq!(format!("{:#X}", a), "0xC");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("12".parse::<FieldElement<17>>(), Ok(FieldElement::new(12)));
+        assert_eq!("0x10".parse::<FieldElement<17>>(), Ok(FieldElement::new(16)));
+        assert_eq!(" 0XA ".parse::<FieldElement<17>>(), Ok(FieldElement::new(10)));
+        assert_eq!(
+            "17".parse::<FieldElement<17>>(),
+            Err(FieldError::NonCanonical { value: 17, modulus: 17 })
+        );
+        assert_eq!("-1".parse::<FieldElement<17>>(), Err(FieldError::InvalidLiteral("-1".to_string())));
+        assert_eq!("0xzz".parse::<FieldElement<17>>(), Err(FieldError::InvalidLiteral("0xzz".to_string())));
+
+        let a = F::new(0xdead_beef_1234);
+        assert_eq!(a.to_string().parse::<F>(), Ok(a));
+        assert_eq!(format!("{:#x}", a).parse::<F>(), Ok(a));
+    }
+}
+
+#[cfg(test)]
+mod test_sampling {
+    use super::*;
+    use crate::fields::{BabyBear, Goldilocks, Mersenne31};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    /// Hands out a fixed sequence of u64 draws.
+    struct Scripted(Vec<u64>);
+
+    impl RngCore for Scripted {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0.remove(0)
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dest)
+        }
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_seeded_sampling_is_reproducible() {
+        let mut a = ChaCha20Rng::seed_from_u64(7);
+        let mut b = ChaCha20Rng::seed_from_u64(7);
+        for _ in 0..100 {
+            assert_eq!(FieldElement::<17>::random_with(&mut a), FieldElement::<17>::random_with(&mut b));
+            assert_eq!(Goldilocks::random_with(&mut a), Goldilocks::random_with(&mut b));
+            assert_eq!(BabyBear::random_with(&mut a), BabyBear::random_with(&mut b));
+        }
+        let x: FieldElement<17> = rand::Rng::gen(&mut a);
+        let y: FieldElement<17> = rand::Rng::gen(&mut b);
+        assert_eq!(x, y);
+    }
+
+    #[test]
+    fn test_rejects_biased_tail() {
+        // 2^64 = 2 (mod 7): the top two u64 values would favour 0 and 1.
+        let mut rng = Scripted(vec![u64::MAX, u64::MAX - 1, u64::MAX - 2, 0]);
+        assert_eq!(FieldElement::<7>::random_with(&mut rng).value(), 6);
+        let mut rng = Scripted(vec![Goldilocks::MODULUS, u64::MAX, 5]);
+        assert_eq!(Goldilocks::random_with(&mut rng).value(), 5);
+        // Mersenne31 keeps the top 31 bits of a u32 draw and retries p.
+        let mut rng = Scripted(vec![u32::MAX as u64, 6]);
+        assert_eq!(Mersenne31::random_with(&mut rng).value(), 3);
+    }
+
+    #[test]
+    fn test_roughly_uniform() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let mut counts = [0usize; 7];
+        for _ in 0..70_000 {
+            counts[FieldElement::<7>::random_with(&mut rng).value() as usize] += 1;
+        }
+        assert!(counts.iter().all(|&c| (9_000..11_000).contains(&c)));
+    }
+}
+
+#[cfg(test)]
+mod test_constant_time {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    use subtle::ConditionallyNegatable;
+
+    const P: u64 = 0xFFFF_FFFF_0000_0001;
+    type F = FieldElement<P>;
+
+    /// Plain square-and-multiply, for comparison with the ladder.
+    fn pow_vartime(a: F, mut e: u64) -> F {
+        let (mut result, mut base) = (F::one(), a);
+        while e > 0 {
+            if e & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        result
+    }
+
+    #[test]
+    fn test_ladder_matches_square_and_multiply() {
+        let mut rng = ChaCha20Rng::seed_from_u64(11);
+        for _ in 0..200 {
+            let a = F::random_with(&mut rng);
+            let e = rng.next_u64();
+            assert_eq!(a.pow(e), pow_vartime(a, e));
+        }
+        assert_eq!(F::new(5).pow(0), F::one());
+        assert_eq!(F::zero().pow(0), F::one());
+        assert_eq!(F::zero().pow(3), F::zero());
+        assert_eq!(F::new(3).pow(u64::MAX), pow_vartime(F::new(3), u64::MAX));
+    }
+
+    #[test]
+    fn test_conditional_select_and_negate() {
+        let (a, b) = (F::new(3), F::new(P - 4));
+        assert_eq!(F::conditional_select(&a, &b, Choice::from(0)), a);
+        assert_eq!(F::conditional_select(&a, &b, Choice::from(1)), b);
+
+        let mut c = a;
+        c.conditional_negate(Choice::from(0));
+        assert_eq!(c, a);
+        c.conditional_negate(Choice::from(1));
+        assert_eq!(c, -a);
+        assert_eq!(-&F::zero(), F::zero());
+    }
+
+    #[test]
+    fn test_ct_inverse() {
+        let a = F::new(0x1234_5678);
+        let inv = a.ct_inverse();
+        assert!(bool::from(inv.is_some()));
+        assert_eq!(inv.unwrap() * a, F::one());
+        assert!(bool::from(F::zero().ct_inverse().is_none()));
+        assert!(bool::from(F::new(1).ct_eq(&F::one())));
+    }
+
+    #[test]
+    fn test_branch_free_add_sub_edges() {
+        let max = F::new(P - 1);
+        assert_eq!((max + max).value(), P - 2);
+        assert_eq!((max + F::one()).value(), 0);
+        assert_eq!((F::zero() - F::one()).value(), P - 1);
+        assert_eq!((F::one() - F::one()).value(), 0);
+        assert_eq!((-F::zero()).value(), 0);
+        assert_eq!((FieldElement::<7>::new(3) + FieldElement::new(3)).value(), 6);
+        assert_eq!((FieldElement::<7>::new(4) + FieldElement::new(3)).value(), 0);
+    }
+
+    fn check_barrett<const M: u64>(rng: &mut ChaCha20Rng) {
+        let top = M as u128 - 1;
+        let mut inputs = vec![0, 1, M as u128, top, top * top, u64::MAX as u128];
+        inputs.extend((0..500).map(|_| rng.next_u64() as u128 % (M as u128) * (rng.next_u64() as u128 % M as u128)));
+        for x in inputs {
+            assert_eq!(FieldElement::<M>::reduce(x) as u128, x % M as u128, "x = {x}, M = {M}");
+        }
+    }
+
+    #[test]
+    fn test_barrett_matches_remainder() {
+        let mut rng = ChaCha20Rng::seed_from_u64(111);
+        check_barrett::<2>(&mut rng);
+        check_barrett::<17>(&mut rng);
+        check_barrett::<{ 1 << 32 }>(&mut rng);
+        check_barrett::<P>(&mut rng);
+        check_barrett::<{ u64::MAX - 58 }>(&mut rng);
+        check_barrett::<{ u64::MAX }>(&mut rng);
+    }
+}
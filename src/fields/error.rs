@@ -0,0 +1,27 @@
+use alloy::primitives::U256;
+use thiserror::Error;
+
+/// Errors reported by field parameter discovery and parsing.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum FieldError {
+    #[error("modulus {0} is not prime")]
+    NotPrime(u64),
+
+    #[error("no primitive root of unity of order {order}: it does not divide p - 1 = {group_order}")]
+    NoRootOfUnity { order: u64, group_order: u64 },
+
+    #[error("expected {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("value {value} is not reduced modulo {modulus}")]
+    NonCanonical { value: u64, modulus: u64 },
+
+    #[error("value {value} is not reduced modulo {modulus}")]
+    NonCanonicalU256 { value: U256, modulus: U256 },
+
+    #[error("expected modulus {expected}, got {actual}")]
+    ModulusMismatch { expected: u64, actual: u64 },
+
+    #[error("invalid integer literal: {0}")]
+    InvalidLiteral(String),
+}
@@ -0,0 +1,536 @@
+//! Binomial extensions of the prime field, `GF(p)[X] / (X^D - W)`.
+//!
+//! Only binomial moduli `X^D - W` are supported; the configurable part of the
+//! irreducible polynomial is the const parameter `W`, e.g.
+//! `Ext2<{ Goldilocks::MODULUS }, 7>` is `GF(p)[X] / (X^2 - 7)`.
+//! [`ExtensionField::IRREDUCIBLE`] says whether a choice of `W` gives a field,
+//! and Frobenius, norm and inversion refuse to compile when it does not.
+//! Challenges drawn from the extension have soundness error `1 / p^D` instead
+//! of `1 / p`.
+
+use crate::fields::montgomery::const_pow_mod;
+use crate::fields::{Field, FieldElement, FieldError};
+use alloy::primitives::U256;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use rand_core::{OsRng, RngCore};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Quadratic extension `GF(M)[X] / (X^2 - W)`.
+pub type Ext2<const M: u64, const W: u64> = ExtensionField<M, 2, W>;
+/// Cubic extension `GF(M)[X] / (X^3 - W)`.
+pub type Ext3<const M: u64, const W: u64> = ExtensionField<M, 3, W>;
+/// Quartic extension `GF(M)[X] / (X^4 - W)`.
+pub type Ext4<const M: u64, const W: u64> = ExtensionField<M, 4, W>;
+
+/// Whether `X^d - w` is irreducible over `GF(m)`, by the classical criterion:
+/// for every prime `r | d`, `r | m - 1` and `w` is not an `r`-th power; and if
+/// `4 | d` then `m = 1 (mod 4)`.
+const fn binomial_is_irreducible(m: u64, d: u64, w: u64) -> bool {
+    let w = w % m;
+    if w == 0 || (d.is_multiple_of(4) && m % 4 != 1) {
+        return false;
+    }
+    let mut n = d;
+    let mut r = 2;
+    while n > 1 {
+        if n.is_multiple_of(r) {
+            if !(m - 1).is_multiple_of(r) || const_pow_mod(w, (m - 1) / r, m) == 1 {
+                return false;
+            }
+            while n.is_multiple_of(r) {
+                n /= r;
+            }
+        }
+        r += 1;
+    }
+    true
+}
+
+/// A field that contains `B` as a subfield, spanned by `DEGREE` base coordinates.
+pub trait ExtensionOf<B: Field>: Field + From<B> {
+    const DEGREE: usize;
+
+    /// Builds an element from its coordinates `c_0 + c_1 X + ...`.
+    /// Panics if `coeffs.len() != DEGREE`.
+    fn from_base_coeffs(coeffs: &[B]) -> Self;
+
+    /// Multiplies by a base-field scalar without embedding it first.
+    fn mul_base(&self, scalar: &B) -> Self;
+}
+
+/// `c_0 + c_1 X + ... + c_{D-1} X^{D-1}` with `X^D = W`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExtensionField<const M: u64, const D: usize, const W: u64> {
+    coeffs: [FieldElement<M>; D],
+}
+
+impl<const M: u64, const D: usize, const W: u64> ExtensionField<M, D, W> {
+    pub fn new(coeffs: [FieldElement<M>; D]) -> Self {
+        ExtensionField { coeffs }
+    }
+
+    /// Embeds a base-field element as the constant `a + 0 X + ...`.
+    pub fn from_base(value: FieldElement<M>) -> Self {
+        let mut coeffs = [FieldElement::zero(); D];
+        coeffs[0] = value;
+        ExtensionField { coeffs }
+    }
+
+    pub fn zero() -> Self {
+        ExtensionField {
+            coeffs: [FieldElement::zero(); D],
+        }
+    }
+
+    pub fn one() -> Self {
+        Self::from_base(FieldElement::one())
+    }
+
+    pub fn coeffs(&self) -> &[FieldElement<M>; D] {
+        &self.coeffs
+    }
+
+    /// `Some(a)` if the element lies in the base field.
+    pub fn to_base(&self) -> Option<FieldElement<M>> {
+        if self.coeffs[1..].iter().all(|c| *c == FieldElement::zero()) {
+            Some(self.coeffs[0])
+        } else {
+            None
+        }
+    }
+
+    pub fn random() -> Self {
+        Self::random_with(&mut OsRng)
+    }
+
+    /// Uniform sample: each coordinate is drawn independently from `rng`.
+    pub fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        let mut coeffs = [FieldElement::zero(); D];
+        for c in coeffs.iter_mut() {
+            *c = FieldElement::random_with(rng);
+        }
+        ExtensionField { coeffs }
+    }
+
+    /// Whether `X^D - W` is irreducible over `GF(M)`, i.e. whether this type is a field.
+    pub const IRREDUCIBLE: bool = binomial_is_irreducible(M, D as u64, W);
+
+    /// Evaluated by every operation that relies on `X^D - W` being irreducible
+    /// (Frobenius, norm, inversion), so that instantiating one of those for a
+    /// reducible choice of `W` fails to compile instead of giving wrong answers.
+    const ASSERT_IRREDUCIBLE: () = assert!(Self::IRREDUCIBLE, "X^D - W is reducible over GF(M), so this is not a field");
+
+    /// [`Self::IRREDUCIBLE`], for callers that prefer a function.
+    pub fn is_irreducible() -> bool {
+        Self::IRREDUCIBLE
+    }
+
+    /// `W^((M-1)/D)`, the factor picked up by `X` under the Frobenius map.
+    fn frobenius_gamma() -> FieldElement<M> {
+        let () = Self::ASSERT_IRREDUCIBLE;
+        FieldElement::<M>::new(W).pow((M - 1) / D as u64)
+    }
+
+    /// The Frobenius automorphism `a -> a^M`.
+    ///
+    /// Since `X^M = X * W^((M-1)/D)`, it scales the `i`-th coordinate by `gamma^i`.
+    pub fn frobenius(&self) -> Self {
+        let gamma = Self::frobenius_gamma();
+        let mut factor = FieldElement::one();
+        let mut coeffs = self.coeffs;
+        for c in coeffs.iter_mut() {
+            *c *= factor;
+            factor *= gamma;
+        }
+        ExtensionField { coeffs }
+    }
+
+    /// Applies the Frobenius map `k` times, i.e. `a -> a^(M^k)`.
+    pub fn frobenius_pow(&self, k: usize) -> Self {
+        let mut result = *self;
+        for _ in 0..(k % D) {
+            result = result.frobenius();
+        }
+        result
+    }
+
+    /// The field norm down to `GF(M)`: the product of all conjugates.
+    pub fn norm(&self) -> FieldElement<M> {
+        let mut result = *self;
+        let mut conjugate = *self;
+        for _ in 1..D {
+            conjugate = conjugate.frobenius();
+            result *= conjugate;
+        }
+        result.coeffs[0]
+    }
+
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    pub fn pow(&self, exp: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = *self;
+        let mut e = exp;
+
+        while e > 0 {
+            if e & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        result
+    }
+
+    /// Inverse via the norm: `a^{-1} = (phi(a) * ... * phi^{D-1}(a)) / N(a)`,
+    /// which costs one base-field inversion.
+    pub fn inverse(&self) -> Self {
+        let () = Self::ASSERT_IRREDUCIBLE;
+        assert!(*self != Self::zero(), "Cannot invert zero in an extension field");
+        let mut others = Self::one();
+        let mut conjugate = *self;
+        for _ in 1..D {
+            conjugate = conjugate.frobenius();
+            others *= conjugate;
+        }
+        let norm = (*self * others).coeffs[0];
+        others.scale(norm.inverse())
+    }
+
+    fn scale(mut self, scalar: FieldElement<M>) -> Self {
+        for c in self.coeffs.iter_mut() {
+            *c *= scalar;
+        }
+        self
+    }
+
+    /// Concatenation of the big-endian coordinates, lowest degree first.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.coeffs.iter().flat_map(|c| c.to_bytes()).collect()
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> Add for ExtensionField<M, D, W> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> AddAssign for ExtensionField<M, D, W> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.coeffs.iter_mut().zip(rhs.coeffs) {
+            *a += b;
+        }
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> Sub for ExtensionField<M, D, W> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> SubAssign for ExtensionField<M, D, W> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.coeffs.iter_mut().zip(rhs.coeffs) {
+            *a -= b;
+        }
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> Mul for ExtensionField<M, D, W> {
+    type Output = Self;
+
+    /// Schoolbook product, folding `X^(D+k)` back to `W * X^k`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let w = FieldElement::<M>::new(W);
+        let mut coeffs = [FieldElement::zero(); D];
+        for i in 0..D {
+            for j in 0..D {
+                let term = self.coeffs[i] * rhs.coeffs[j];
+                if i + j < D {
+                    coeffs[i + j] += term;
+                } else {
+                    coeffs[i + j - D] += w * term;
+                }
+            }
+        }
+        ExtensionField { coeffs }
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> MulAssign for ExtensionField<M, D, W> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> Div for ExtensionField<M, D, W> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> DivAssign for ExtensionField<M, D, W> {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self *= rhs.inverse();
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> Neg for ExtensionField<M, D, W> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        for c in self.coeffs.iter_mut() {
+            *c = -*c;
+        }
+        self
+    }
+}
+
+// Mixed arithmetic with the base field.
+
+impl<const M: u64, const D: usize, const W: u64> Add<FieldElement<M>> for ExtensionField<M, D, W> {
+    type Output = Self;
+
+    fn add(mut self, rhs: FieldElement<M>) -> Self::Output {
+        self.coeffs[0] += rhs;
+        self
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> Sub<FieldElement<M>> for ExtensionField<M, D, W> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: FieldElement<M>) -> Self::Output {
+        self.coeffs[0] -= rhs;
+        self
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> Mul<FieldElement<M>> for ExtensionField<M, D, W> {
+    type Output = Self;
+
+    fn mul(self, rhs: FieldElement<M>) -> Self::Output {
+        self.scale(rhs)
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> From<FieldElement<M>> for ExtensionField<M, D, W> {
+    fn from(value: FieldElement<M>) -> Self {
+        Self::from_base(value)
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> From<i128> for ExtensionField<M, D, W> {
+    fn from(value: i128) -> Self {
+        Self::from_base(FieldElement::from(value))
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> Distribution<ExtensionField<M, D, W>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ExtensionField<M, D, W> {
+        ExtensionField::random_with(rng)
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> Field for ExtensionField<M, D, W> {
+    fn zero() -> Self {
+        ExtensionField::zero()
+    }
+
+    fn one() -> Self {
+        ExtensionField::one()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Self::from_base(FieldElement::new(value))
+    }
+
+    fn inverse(&self) -> Self {
+        ExtensionField::inverse(self)
+    }
+
+    fn pow(&self, exp: u64) -> Self {
+        ExtensionField::pow(self, exp)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        ExtensionField::to_bytes(self)
+    }
+
+    /// Expects `8 * D` bytes: the canonical encodings of the coordinates.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FieldError> {
+        if bytes.len() != 8 * D {
+            return Err(FieldError::InvalidLength {
+                expected: 8 * D,
+                actual: bytes.len(),
+            });
+        }
+        let mut coeffs = [FieldElement::zero(); D];
+        for (c, chunk) in coeffs.iter_mut().zip(bytes.chunks_exact(8)) {
+            *c = FieldElement::from_bytes(chunk)?;
+        }
+        Ok(ExtensionField { coeffs })
+    }
+
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        ExtensionField::random_with(rng)
+    }
+
+    fn characteristic() -> U256 {
+        U256::from(M)
+    }
+
+    fn square(&self) -> Self {
+        ExtensionField::square(self)
+    }
+}
+
+impl<const M: u64, const D: usize, const W: u64> ExtensionOf<FieldElement<M>> for ExtensionField<M, D, W> {
+    const DEGREE: usize = D;
+
+    fn from_base_coeffs(coeffs: &[FieldElement<M>]) -> Self {
+        let coeffs: [FieldElement<M>; D] = coeffs
+            .try_into()
+            .expect("wrong number of base coordinates for this extension");
+        ExtensionField { coeffs }
+    }
+
+    fn mul_base(&self, scalar: &FieldElement<M>) -> Self {
+        self.scale(*scalar)
+    }
+}
+
+#[cfg(test)]
+mod test_extension {
+    use super::*;
+    use crate::channel::channel::Channel;
+    use crate::fields::Goldilocks;
+    use crate::polynomial::Polynomial;
+
+    // 3 is a non-residue mod 7, so this is GF(49).
+    type F49 = Ext2<7, 3>;
+    // 2 is not a cube mod 7, so this is GF(343).
+    type F343 = Ext3<7, 2>;
+    // 13 = 1 (mod 4) and 2 is a non-residue mod 13, so this is GF(13^4).
+    type F13_4 = Ext4<13, 2>;
+    type GoldilocksExt2 = Ext2<{ Goldilocks::MODULUS }, 7>;
+
+    fn all_f49() -> Vec<F49> {
+        let mut elements = Vec::new();
+        for a in 0..7 {
+            for b in 0..7 {
+                elements.push(F49::new([FieldElement::new(a), FieldElement::new(b)]));
+            }
+        }
+        elements
+    }
+
+    #[test]
+    fn test_irreducibility() {
+        assert!(F49::is_irreducible());
+        assert!(F343::is_irreducible());
+        assert!(F13_4::is_irreducible());
+        assert!(GoldilocksExt2::is_irreducible());
+        // 2 = 3^2 mod 7, so X^2 - 2 factors.
+        assert!(!Ext2::<7, 2>::is_irreducible());
+        // 7 = 3 (mod 4): X^4 - W is never irreducible.
+        assert!(!Ext4::<7, 3>::is_irreducible());
+    }
+
+    #[test]
+    fn test_inverse_of_every_element() {
+        for a in all_f49().into_iter().skip(1) {
+            assert_eq!(a * a.inverse(), F49::one());
+            assert_eq!(a / a, F49::one());
+        }
+    }
+
+    #[test]
+    fn test_multiplicative_group_order() {
+        let a = F343::new([FieldElement::new(3), FieldElement::new(1), FieldElement::new(5)]);
+        assert_eq!(a.pow(343 - 1), F343::one());
+        let b = F13_4::new([2, 0, 7, 1].map(FieldElement::new));
+        assert_eq!(b.pow(13u64.pow(4) - 1), F13_4::one());
+        assert_eq!(b * b.inverse(), F13_4::one());
+    }
+
+    #[test]
+    fn test_frobenius_is_pow_p() {
+        let a = F343::new([FieldElement::new(4), FieldElement::new(6), FieldElement::new(2)]);
+        assert_eq!(a.frobenius(), a.pow(7));
+        assert_eq!(a.frobenius_pow(3), a);
+        let b = GoldilocksExt2::random();
+        assert_eq!(b.frobenius(), b.pow(Goldilocks::MODULUS));
+    }
+
+    #[test]
+    fn test_norm_lies_in_base_field() {
+        let a = F13_4::new([5, 1, 0, 9].map(FieldElement::new));
+        let n = a.norm();
+        assert_eq!(F13_4::from(n), a.pow((13u64.pow(4) - 1) / 12));
+        assert_eq!(a.norm() * a.inverse().norm(), FieldElement::one());
+    }
+
+    #[test]
+    fn test_embedding_and_mixed_ops() {
+        let x = FieldElement::<7>::new(5);
+        let a = F49::from(x);
+        assert_eq!(a.to_base(), Some(x));
+        assert_eq!(F49::from(x) * F49::from(x), F49::from(x * x));
+        let b = F49::new([FieldElement::new(1), FieldElement::new(2)]);
+        assert_eq!(b.to_base(), None);
+        assert_eq!(b * x, b * F49::from(x));
+        assert_eq!(b + x - x, b);
+        assert_eq!(-b + b, F49::zero());
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let a = GoldilocksExt2::random();
+        let bytes = Field::to_bytes(&a);
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(<GoldilocksExt2 as Field>::from_bytes(&bytes), Ok(a));
+        assert_eq!(
+            <GoldilocksExt2 as Field>::from_bytes(&bytes[..8]),
+            Err(FieldError::InvalidLength { expected: 16, actual: 8 })
+        );
+    }
+
+    #[test]
+    fn test_channel_extension_challenge() {
+        let mut channel = Channel::<FieldElement<7>>::new();
+        let challenge: F343 = channel.receive_random_extension_element();
+        assert_eq!(channel.proof.len(), 3);
+
+        let mut replay = Channel::<FieldElement<7>>::new();
+        let coeffs: Vec<_> = (0..3).map(|_| replay.receive_random_field_element()).collect();
+        assert_eq!(challenge, F343::from_base_coeffs(&coeffs));
+    }
+
+    #[test]
+    fn test_out_of_domain_evaluation() {
+        let p = Polynomial::new(vec![FieldElement::<7>::new(1), FieldElement::new(2), FieldElement::new(3)]);
+        let z = F49::new([FieldElement::new(2), FieldElement::new(5)]);
+        let expected = F49::one() + z * FieldElement::new(2) + z * z * FieldElement::new(3);
+        assert_eq!(p.evaluate_extension(&z), expected);
+        assert_eq!(
+            p.evaluate_extension(&F49::from(FieldElement::new(4))),
+            F49::from(p.evaluate(FieldElement::new(4)))
+        );
+    }
+}
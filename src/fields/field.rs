@@ -0,0 +1,230 @@
+use crate::fields::FieldError;
+use alloy::primitives::U256;
+use rand_core::{OsRng, RngCore};
+use rayon::prelude::*;
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Slice length handled by one rayon task in [`Field::batch_inverse_parallel`].
+/// Each chunk pays for one full inversion, so chunks should stay large.
+pub const BATCH_INVERSE_CHUNK: usize = 1 << 12;
+
+/// The arithmetic every field used by the prover has to provide.
+///
+/// `Polynomial`, `Channel`, `MerkleTree` and `CosetFri` are written against this
+/// trait instead of a concrete `FieldElement<MODULUS>`, so extension fields,
+/// larger prime fields and specialised fast fields can be plugged in without
+/// touching the polynomial and FRI code.
+pub trait Field:
+    Clone
+    + Debug
+    + PartialEq
+    + Eq
+    + Send
+    + Sync
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + MulAssign
+    + Div<Output = Self>
+    + DivAssign
+    + Neg<Output = Self>
+{
+    /// Additive identity.
+    fn zero() -> Self;
+
+    /// Multiplicative identity.
+    fn one() -> Self;
+
+    /// The image of the integer `value` in the field, i.e. `value * 1`.
+    fn from_u64(value: u64) -> Self;
+
+    /// Multiplicative inverse. Panics (or returns zero) on zero, depending on the field.
+    fn inverse(&self) -> Self;
+
+    /// Canonical byte encoding of the element.
+    fn to_bytes(&self) -> Vec<u8>;
+
+    /// Parses the canonical encoding produced by [`Field::to_bytes`], rejecting
+    /// wrong lengths and non-reduced values.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FieldError>;
+
+    /// Uniformly random element drawn from `rng`, so tests and benchmarks can be
+    /// reproduced from a seed.
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self;
+
+    /// Uniformly random element from the operating system's RNG.
+    fn random() -> Self {
+        Self::random_with(&mut OsRng)
+    }
+
+    /// Characteristic of the field (the prime `p` for `GF(p^k)`).
+    fn characteristic() -> U256;
+
+    /// Largest `k` such that `2^k` divides the order of the multiplicative group.
+    /// Zero if unknown.
+    fn two_adicity() -> u32 {
+        0
+    }
+
+    /// A primitive `2^log_n`-th root of unity, or `None` if the field has none (or
+    /// does not know it).
+    fn two_adic_root_of_unity(_log_n: u32) -> Option<Self> {
+        None
+    }
+
+    /// A generator of the multiplicative group, used as the default coset offset.
+    fn multiplicative_generator() -> Option<Self> {
+        None
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    fn square(&self) -> Self {
+        self.clone() * self.clone()
+    }
+
+    fn double(&self) -> Self {
+        self.clone() + self.clone()
+    }
+
+    /// Square-and-multiply exponentiation.
+    fn pow(&self, exp: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = self.clone();
+        let mut e = exp;
+        while e > 0 {
+            if e & 1 == 1 {
+                result *= base.clone();
+            }
+            base = base.square();
+            e >>= 1;
+        }
+        result
+    }
+
+    /// Interprets `bytes` as a big-endian integer and maps it into the field.
+    /// Used by the channel to turn a hash digest into a challenge.
+    fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
+        let base = Self::from_u64(256);
+        bytes.iter().fold(Self::zero(), |acc, &b| acc * base.clone() + Self::from_u64(b as u64))
+    }
+
+    /// `lhs[i] += rhs[i]`. Panics if the lengths differ. Fields with a vectorised
+    /// backend override the slice helpers.
+    fn add_slices(lhs: &mut [Self], rhs: &[Self]) {
+        assert_eq!(lhs.len(), rhs.len(), "slice lengths differ");
+        for (a, b) in lhs.iter_mut().zip(rhs) {
+            *a += b.clone();
+        }
+    }
+
+    /// `lhs[i] -= rhs[i]`. Panics if the lengths differ.
+    fn sub_slices(lhs: &mut [Self], rhs: &[Self]) {
+        assert_eq!(lhs.len(), rhs.len(), "slice lengths differ");
+        for (a, b) in lhs.iter_mut().zip(rhs) {
+            *a -= b.clone();
+        }
+    }
+
+    /// `lhs[i] *= rhs[i]`. Panics if the lengths differ.
+    fn mul_slices(lhs: &mut [Self], rhs: &[Self]) {
+        assert_eq!(lhs.len(), rhs.len(), "slice lengths differ");
+        for (a, b) in lhs.iter_mut().zip(rhs) {
+            *a *= b.clone();
+        }
+    }
+
+    /// `values[i] *= scalar`.
+    fn scale_slice(values: &mut [Self], scalar: &Self) {
+        for v in values.iter_mut() {
+            *v *= scalar.clone();
+        }
+    }
+
+    /// Inverts every element of `values` in place with Montgomery's trick:
+    /// one inversion plus `3(n-1)` multiplications instead of `n` inversions.
+    /// Zero entries are skipped and stay zero.
+    fn batch_inverse(values: &mut [Self]) {
+        // prefix[i] = product of the non-zero values before index i.
+        let mut prefix = Vec::with_capacity(values.len());
+        let mut acc = Self::one();
+        for v in values.iter() {
+            prefix.push(acc.clone());
+            if !v.is_zero() {
+                acc *= v.clone();
+            }
+        }
+
+        // Walk back, peeling one factor off the inverted product per step.
+        let mut acc_inv = acc.inverse();
+        for (v, before) in values.iter_mut().zip(prefix).rev() {
+            if v.is_zero() {
+                continue;
+            }
+            let v_inv = acc_inv.clone() * before;
+            acc_inv *= v.clone();
+            *v = v_inv;
+        }
+    }
+
+    /// [`Field::batch_inverse`] over chunks of [`BATCH_INVERSE_CHUNK`] elements in parallel.
+    fn batch_inverse_parallel(values: &mut [Self]) {
+        values
+            .par_chunks_mut(BATCH_INVERSE_CHUNK)
+            .for_each(|chunk| Self::batch_inverse(chunk));
+    }
+}
+
+#[cfg(test)]
+mod test_batch_inverse {
+    use super::*;
+    use crate::fields::{FieldElement, Goldilocks};
+
+    type F = FieldElement<2147483647>;
+
+    #[test]
+    fn test_matches_single_inversions() {
+        let original: Vec<F> = (0..100).map(|i| F::new(i * 7919 + 1)).collect();
+        let mut values = original.clone();
+        F::batch_inverse(&mut values);
+        for (v, inv) in original.iter().zip(&values) {
+            assert_eq!(*inv, v.inverse());
+        }
+    }
+
+    #[test]
+    fn test_zeros_stay_zero() {
+        let mut values = vec![F::zero(), F::new(3), F::zero(), F::new(5), F::zero()];
+        F::batch_inverse(&mut values);
+        assert_eq!(values[0], F::zero());
+        assert_eq!(values[2], F::zero());
+        assert_eq!(values[4], F::zero());
+        assert_eq!(values[1] * F::new(3), F::one());
+        assert_eq!(values[3] * F::new(5), F::one());
+
+        let mut all_zero = vec![F::zero(); 4];
+        F::batch_inverse(&mut all_zero);
+        assert!(all_zero.iter().all(|v| v.is_zero()));
+
+        let mut empty: Vec<F> = vec![];
+        F::batch_inverse(&mut empty);
+    }
+
+    #[test]
+    fn test_parallel_matches_serial() {
+        let n = 3 * BATCH_INVERSE_CHUNK + 17;
+        let original: Vec<Goldilocks> = (0..n as u64).map(|i| Goldilocks::new(i * i)).collect();
+        let mut serial = original.clone();
+        let mut parallel = original.clone();
+        Goldilocks::batch_inverse(&mut serial);
+        Goldilocks::batch_inverse_parallel(&mut parallel);
+        assert_eq!(serial, parallel);
+        assert_eq!(parallel[0], Goldilocks::zero());
+        assert_eq!(parallel[n - 1] * original[n - 1], Goldilocks::one());
+    }
+}
@@ -0,0 +1,342 @@
+//! The Goldilocks field, `p = 2^64 - 2^32 + 1`.
+//!
+//! Because `2^64 = 2^32 - 1 (mod p)` and `2^96 = -1 (mod p)`, a 128-bit product
+//! reduces with a few 64-bit adds and subtracts instead of a `u128 %`.
+
+use crate::fields::{Field, FieldElement, FieldError};
+use alloy::primitives::U256;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use rand_core::{OsRng, RngCore};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use subtle::ConstantTimeEq;
+
+/// `2^32 - 1`, i.e. `2^64 mod p`.
+const EPSILON: u64 = 0xFFFF_FFFF;
+
+/// An element of the Goldilocks field, always kept in canonical form `[0, p)`.
+#[derive(Debug, Clone, Copy)]
+pub struct Goldilocks {
+    value: u64,
+}
+
+impl Goldilocks {
+    /// `2^64 - 2^32 + 1`.
+    pub const MODULUS: u64 = 0xFFFF_FFFF_0000_0001;
+
+    pub fn new(value: u64) -> Self {
+        Goldilocks {
+            value: Self::canonicalize(value),
+        }
+    }
+
+    pub fn zero() -> Self {
+        Goldilocks { value: 0 }
+    }
+
+    pub fn one() -> Self {
+        Goldilocks { value: 1 }
+    }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn random() -> Self {
+        Self::random_with(&mut OsRng)
+    }
+
+    /// Uniform sample: draws of `p` or above (probability about `2^-32`) are retried.
+    pub fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        loop {
+            let x = rng.next_u64();
+            if x < Self::MODULUS {
+                return Goldilocks { value: x };
+            }
+        }
+    }
+
+    pub fn pow(&self, exp: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = *self;
+        let mut e = exp;
+
+        while e > 0 {
+            if e & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        result
+    }
+
+    /// `a^(p-2) % p`.
+    pub fn inverse(&self) -> Self {
+        self.pow(Self::MODULUS - 2)
+    }
+
+    pub fn to_bytes(&self) -> [u8; 8] {
+        self.value.to_be_bytes() //big endian
+    }
+
+    #[inline(always)]
+    fn canonicalize(value: u64) -> u64 {
+        // Any u64 is below 2p, so a single subtraction is enough.
+        if value >= Self::MODULUS {
+            value - Self::MODULUS
+        } else {
+            value
+        }
+    }
+
+    /// Reduces a 128-bit value using `2^64 = EPSILON` and `2^96 = -1 (mod p)`.
+    #[inline(always)]
+    fn reduce128(x: u128) -> u64 {
+        let x_lo = x as u64;
+        let x_hi = (x >> 64) as u64;
+        let x_hi_hi = x_hi >> 32;
+        let x_hi_lo = x_hi & EPSILON;
+
+        // x_lo - x_hi_hi (the 2^96 part)
+        let (mut t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
+        if borrow {
+            t0 = t0.wrapping_sub(EPSILON);
+        }
+        // + x_hi_lo * (2^32 - 1) (the 2^64 part), fits in u64
+        let t1 = x_hi_lo * EPSILON;
+        let (t2, carry) = t0.overflowing_add(t1);
+        let t2 = t2.wrapping_add(EPSILON * carry as u64);
+        Self::canonicalize(t2)
+    }
+}
+
+impl PartialEq for Goldilocks {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.ct_eq(&other.value).unwrap_u8() == 1
+    }
+}
+
+impl Eq for Goldilocks {}
+
+impl Hash for Goldilocks {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl Add for Goldilocks {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (sum, carry) = self.value.overflowing_add(rhs.value);
+        // On wrap-around the lost 2^64 is worth EPSILON; this cannot wrap again
+        // because both operands are below p.
+        let sum = if carry { sum.wrapping_add(EPSILON) } else { sum };
+        Goldilocks {
+            value: Self::canonicalize(sum),
+        }
+    }
+}
+
+impl AddAssign for Goldilocks {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Goldilocks {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (diff, borrow) = self.value.overflowing_sub(rhs.value);
+        let value = if borrow {
+            diff.wrapping_add(Self::MODULUS)
+        } else {
+            diff
+        };
+        Goldilocks { value }
+    }
+}
+
+impl SubAssign for Goldilocks {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Goldilocks {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Goldilocks {
+            value: Self::reduce128(self.value as u128 * rhs.value as u128),
+        }
+    }
+}
+
+impl MulAssign for Goldilocks {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for Goldilocks {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+impl DivAssign for Goldilocks {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self *= rhs.inverse();
+    }
+}
+
+impl Neg for Goldilocks {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Goldilocks::zero() - self
+    }
+}
+
+impl From<i128> for Goldilocks {
+    fn from(value: i128) -> Self {
+        Goldilocks::from(FieldElement::<{ Goldilocks::MODULUS }>::from(value))
+    }
+}
+
+impl From<FieldElement<{ Goldilocks::MODULUS }>> for Goldilocks {
+    fn from(value: FieldElement<{ Goldilocks::MODULUS }>) -> Self {
+        Goldilocks { value: value.value() }
+    }
+}
+
+impl From<Goldilocks> for FieldElement<{ Goldilocks::MODULUS }> {
+    fn from(value: Goldilocks) -> Self {
+        FieldElement::new(value.value)
+    }
+}
+
+impl Distribution<Goldilocks> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Goldilocks {
+        Goldilocks::random_with(rng)
+    }
+}
+
+impl Field for Goldilocks {
+    fn zero() -> Self {
+        Goldilocks::zero()
+    }
+
+    fn one() -> Self {
+        Goldilocks::one()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Goldilocks::new(value)
+    }
+
+    fn inverse(&self) -> Self {
+        Goldilocks::inverse(self)
+    }
+
+    fn pow(&self, exp: u64) -> Self {
+        Goldilocks::pow(self, exp)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        Goldilocks::to_bytes(self).to_vec()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FieldError> {
+        <FieldElement<{ Goldilocks::MODULUS }> as Field>::from_bytes(bytes).map(Goldilocks::from)
+    }
+
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Goldilocks::random_with(rng)
+    }
+
+    fn characteristic() -> U256 {
+        U256::from(Goldilocks::MODULUS)
+    }
+
+    /// `p - 1 = 2^32 * 3 * 5 * 17 * 257 * 65537`.
+    fn two_adicity() -> u32 {
+        32
+    }
+
+    fn two_adic_root_of_unity(log_n: u32) -> Option<Self> {
+        if log_n > 32 {
+            return None;
+        }
+        // 7 generates the multiplicative group.
+        Some(Goldilocks::new(7).pow((Self::MODULUS - 1) >> log_n))
+    }
+
+    fn multiplicative_generator() -> Option<Self> {
+        Some(Goldilocks::new(7))
+    }
+}
+
+#[cfg(test)]
+mod test_goldilocks {
+    use super::*;
+    use rand::{RngCore, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+
+    const P: u64 = Goldilocks::MODULUS;
+    type Reference = FieldElement<P>;
+
+    #[test]
+    fn test_new_reduces() {
+        assert_eq!(Goldilocks::new(P).value(), 0);
+        assert_eq!(Goldilocks::new(u64::MAX).value(), u64::MAX - P);
+    }
+
+    #[test]
+    fn test_edge_cases() {
+        let minus_one = Goldilocks::new(P - 1);
+        assert_eq!((minus_one + minus_one).value(), P - 2);
+        assert_eq!((minus_one * minus_one).value(), 1);
+        assert_eq!((Goldilocks::zero() - Goldilocks::one()).value(), P - 1);
+        assert_eq!((-Goldilocks::zero()).value(), 0);
+        // 2^32 * 2^32 = 2^64 = 2^32 - 1
+        let two_32 = Goldilocks::new(1 << 32);
+        assert_eq!((two_32 * two_32).value(), EPSILON);
+        // 2^96 = -1
+        assert_eq!(Goldilocks::new(2).pow(96).value(), P - 1);
+    }
+
+    #[test]
+    fn test_matches_generic_field_element() {
+        let mut rng = ChaCha20Rng::seed_from_u64(64);
+        for _ in 0..1000 {
+            let (x, y) = (rng.next_u64() % P, rng.next_u64() % P);
+            let (a, b) = (Goldilocks::new(x), Goldilocks::new(y));
+            let (c, d) = (Reference::new(x), Reference::new(y));
+            assert_eq!((a + b).value(), (c + d).value());
+            assert_eq!((a - b).value(), (c - d).value());
+            assert_eq!((a * b).value(), (c * d).value());
+            assert_eq!((-a).value(), (-c).value());
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = Goldilocks::new(0x1234_5678_9abc_def0);
+        assert_eq!(a * a.inverse(), Goldilocks::one());
+        assert_eq!((a / a).value(), 1);
+    }
+
+    #[test]
+    fn test_from_signed() {
+        assert_eq!(Goldilocks::from(-1i128).value(), P - 1);
+    }
+}
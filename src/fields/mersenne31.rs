@@ -0,0 +1,320 @@
+//! The Mersenne-31 field, `p = 2^31 - 1`.
+//!
+//! Since `2^31 = 1 (mod p)`, reducing a 62-bit product is just folding the high
+//! bits onto the low bits. The multiplicative group has two-adicity 1, so this
+//! field is mostly useful for hashing and throughput comparisons rather than
+//! radix-2 FFTs.
+
+use crate::fields::{Field, FieldElement, FieldError};
+use alloy::primitives::U256;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use rand_core::{OsRng, RngCore};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use subtle::ConstantTimeEq;
+
+/// An element of `GF(2^31 - 1)`, kept in canonical form `[0, p)`.
+#[derive(Debug, Clone, Copy)]
+pub struct Mersenne31 {
+    value: u32,
+}
+
+impl Mersenne31 {
+    /// `2^31 - 1`.
+    pub const MODULUS: u32 = 0x7fff_ffff;
+
+    pub fn new(value: u32) -> Self {
+        Mersenne31 {
+            value: Self::reduce32(value),
+        }
+    }
+
+    pub fn zero() -> Self {
+        Mersenne31 { value: 0 }
+    }
+
+    pub fn one() -> Self {
+        Mersenne31 { value: 1 }
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    pub fn random() -> Self {
+        Self::random_with(&mut OsRng)
+    }
+
+    /// Uniform sample: 31 random bits, retrying the single value `p`.
+    pub fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        loop {
+            let x = rng.next_u32() >> 1;
+            if x < Self::MODULUS {
+                return Mersenne31 { value: x };
+            }
+        }
+    }
+
+    pub fn pow(&self, exp: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = *self;
+        let mut e = exp;
+
+        while e > 0 {
+            if e & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        result
+    }
+
+    /// `a^(p-2) % p`.
+    pub fn inverse(&self) -> Self {
+        self.pow(Self::MODULUS as u64 - 2)
+    }
+
+    pub fn to_bytes(&self) -> [u8; 4] {
+        self.value.to_be_bytes() //big endian
+    }
+
+    /// Reduces any `u32`: one fold leaves at most `p + 1` (at `x = u32::MAX`),
+    /// so a single conditional subtraction finishes.
+    #[inline(always)]
+    fn reduce32(x: u32) -> u32 {
+        let folded = (x & Self::MODULUS) + (x >> 31);
+        if folded >= Self::MODULUS {
+            folded - Self::MODULUS
+        } else {
+            folded
+        }
+    }
+
+    /// Reduces a product of two canonical values (`x < 2^62`).
+    #[inline(always)]
+    fn reduce64(x: u64) -> u32 {
+        // First fold leaves < 2^32, the second is the u32 fold above.
+        let folded = (x & Self::MODULUS as u64) + (x >> 31);
+        Self::reduce32(folded as u32)
+    }
+}
+
+impl PartialEq for Mersenne31 {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.ct_eq(&other.value).unwrap_u8() == 1
+    }
+}
+
+impl Eq for Mersenne31 {}
+
+impl Hash for Mersenne31 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl Add for Mersenne31 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Mersenne31 {
+            value: Self::reduce32(self.value + rhs.value),
+        }
+    }
+}
+
+impl AddAssign for Mersenne31 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Mersenne31 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (diff, borrow) = self.value.overflowing_sub(rhs.value);
+        Mersenne31 {
+            value: if borrow { diff.wrapping_add(Self::MODULUS) } else { diff },
+        }
+    }
+}
+
+impl SubAssign for Mersenne31 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Mersenne31 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Mersenne31 {
+            value: Self::reduce64(self.value as u64 * rhs.value as u64),
+        }
+    }
+}
+
+impl MulAssign for Mersenne31 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for Mersenne31 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+impl DivAssign for Mersenne31 {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self *= rhs.inverse();
+    }
+}
+
+impl Neg for Mersenne31 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Mersenne31::zero() - self
+    }
+}
+
+impl From<i128> for Mersenne31 {
+    fn from(value: i128) -> Self {
+        let modulus = Self::MODULUS as i128;
+        let mut val = value % modulus;
+        if val < 0 {
+            val += modulus; // ensure in [0, p-1]
+        }
+        Mersenne31::new(val as u32)
+    }
+}
+
+impl From<Mersenne31> for FieldElement<{ Mersenne31::MODULUS as u64 }> {
+    fn from(value: Mersenne31) -> Self {
+        FieldElement::new(value.value as u64)
+    }
+}
+
+impl Distribution<Mersenne31> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Mersenne31 {
+        Mersenne31::random_with(rng)
+    }
+}
+
+impl Field for Mersenne31 {
+    fn zero() -> Self {
+        Mersenne31::zero()
+    }
+
+    fn one() -> Self {
+        Mersenne31::one()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Mersenne31::new((value % Self::MODULUS as u64) as u32)
+    }
+
+    fn inverse(&self) -> Self {
+        Mersenne31::inverse(self)
+    }
+
+    fn pow(&self, exp: u64) -> Self {
+        Mersenne31::pow(self, exp)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        Mersenne31::to_bytes(self).to_vec()
+    }
+
+    /// Expects exactly 4 big-endian bytes holding a value below `p`.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FieldError> {
+        let raw: [u8; 4] = bytes.try_into().map_err(|_| FieldError::InvalidLength {
+            expected: 4,
+            actual: bytes.len(),
+        })?;
+        let value = u32::from_be_bytes(raw);
+        if value >= Self::MODULUS {
+            return Err(FieldError::NonCanonical {
+                value: value as u64,
+                modulus: Self::MODULUS as u64,
+            });
+        }
+        Ok(Mersenne31 { value })
+    }
+
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Mersenne31::random_with(rng)
+    }
+
+    fn characteristic() -> U256 {
+        U256::from(Self::MODULUS)
+    }
+
+    /// `p - 1 = 2 * 3^2 * 7 * 11 * 31 * 151 * 331`: only `-1` is a 2-power root of unity.
+    fn two_adicity() -> u32 {
+        1
+    }
+
+    fn two_adic_root_of_unity(log_n: u32) -> Option<Self> {
+        match log_n {
+            0 => Some(Mersenne31::one()),
+            1 => Some(-Mersenne31::one()),
+            _ => None,
+        }
+    }
+
+    fn multiplicative_generator() -> Option<Self> {
+        Some(Mersenne31::new(7))
+    }
+}
+
+#[cfg(test)]
+mod test_mersenne31 {
+    use super::*;
+    use rand::{RngCore, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+
+    const P: u32 = Mersenne31::MODULUS;
+    type Reference = FieldElement<{ P as u64 }>;
+
+    #[test]
+    fn test_reduction_edges() {
+        assert_eq!(Mersenne31::new(P).value(), 0);
+        assert_eq!(Mersenne31::new(u32::MAX).value(), 1);
+        let minus_one = Mersenne31::new(P - 1);
+        assert_eq!((minus_one + minus_one).value(), P - 2);
+        assert_eq!((minus_one * minus_one).value(), 1);
+        assert_eq!((Mersenne31::one() + minus_one).value(), 0);
+        assert_eq!(Mersenne31::new(2).pow(31).value(), 1);
+    }
+
+    #[test]
+    fn test_matches_reference() {
+        let mut rng = ChaCha20Rng::seed_from_u64(31);
+        for _ in 0..1000 {
+            let (x, y) = (rng.next_u32() % P, rng.next_u32() % P);
+            let (a, b) = (Mersenne31::new(x), Mersenne31::new(y));
+            let (c, d) = (Reference::new(x as u64), Reference::new(y as u64));
+            assert_eq!((a + b).value() as u64, (c + d).value());
+            assert_eq!((a - b).value() as u64, (c - d).value());
+            assert_eq!((a * b).value() as u64, (c * d).value());
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = Mersenne31::new(987654321);
+        assert_eq!(a * a.inverse(), Mersenne31::one());
+        assert_eq!(Mersenne31::from(-2i128).value(), P - 2);
+    }
+}
@@ -1,5 +1,35 @@
+pub mod binary_tower;
+pub mod clmul;
+pub mod dynamic;
 pub mod element; 
+pub mod error;
+pub mod extension;
+pub mod field;
+pub mod goldilocks;
+pub mod mersenne31;
+pub mod montgomery;
+pub mod monty31;
+pub mod packed;
+pub mod params;
+pub mod powers;
+pub mod stark252;
+pub use binary_tower::{
+    BinaryField128b, BinaryField16b, BinaryField1b, BinaryField2b, BinaryField32b, BinaryField4b, BinaryField64b,
+    BinaryField8b, BinaryTower,
+};
+pub use dynamic::{DynFieldElement, FieldContext};
 pub use element::FieldElement;
+pub use error::FieldError;
+pub use extension::{Ext2, Ext3, Ext4, ExtensionField, ExtensionOf};
+pub use field::Field;
+pub use goldilocks::Goldilocks;
+pub use mersenne31::Mersenne31;
+pub use montgomery::MontFieldElement;
+pub use monty31::{BabyBear, KoalaBear, Monty31};
+pub use packed::PackedFieldElement;
+pub use params::FieldParams;
+pub use powers::{powers, powers_table, powers_table_parallel, shifted_powers, FixedBaseTable, Powers};
+pub use stark252::Stark252;
 
 
 // fn main() {
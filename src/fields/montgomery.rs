@@ -0,0 +1,443 @@
+//! Montgomery-form prime field element.
+//!
+//! Values are stored as `a * R mod MODULUS` with `R = 2^64`, so a product needs
+//! one `u128` multiply and a REDC step instead of the Barrett reduction that
+//! [`FieldElement`] uses.
+//! Conversion happens only at the API boundary (`new` / `value`).
+
+use crate::fields::{params, Field, FieldElement, FieldError};
+use alloy::primitives::U256;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use rand_core::{OsRng, RngCore};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use subtle::ConstantTimeEq;
+
+/// `-m^{-1} mod 2^64` by Newton iteration (each step doubles the correct bits).
+pub const fn mont_neg_inv(m: u64) -> u64 {
+    let mut inv = 1u64;
+    let mut i = 0;
+    while i < 6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(m.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+/// `R mod m` with `R = 2^64`.
+pub const fn mont_r(m: u64) -> u64 {
+    ((1u128 << 64) % m as u128) as u64
+}
+
+/// `R^2 mod m` with `R = 2^64`.
+pub const fn mont_r2(m: u64) -> u64 {
+    let r = mont_r(m) as u128;
+    ((r * r) % m as u128) as u64
+}
+
+/// `base^exp mod m`, usable in constants.
+pub const fn const_pow_mod(base: u64, mut exp: u64, m: u64) -> u64 {
+    let m = m as u128;
+    let mut base = base as u128 % m;
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Deterministic Miller-Rabin for 64-bit `n` (the first twelve primes are
+/// enough witnesses below `2^64`), usable in constants.
+pub const fn const_is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        if n.is_multiple_of(WITNESSES[i]) {
+            return n == WITNESSES[i];
+        }
+        i += 1;
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    i = 0;
+    while i < WITNESSES.len() {
+        let mut x = const_pow_mod(WITNESSES[i], d, n);
+        let mut composite = x != 1 && x != n - 1;
+        let mut r = 1;
+        while composite && r < s {
+            x = (x as u128 * x as u128 % n as u128) as u64;
+            composite = x != n - 1;
+            r += 1;
+        }
+        if composite {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Montgomery reduction: returns `t * R^{-1} mod m` for `t < m * 2^64`.
+#[inline(always)]
+pub const fn mont_redc(t: u128, m: u64, neg_inv: u64) -> u64 {
+    let k = (t as u64).wrapping_mul(neg_inv);
+    // t + k*m can exceed 2^128 for moduli close to 2^64, keep the carry.
+    let (sum, carry) = t.overflowing_add(k as u128 * m as u128);
+    let u = (sum >> 64) | ((carry as u128) << 64);
+    if u >= m as u128 {
+        (u - m as u128) as u64
+    } else {
+        u as u64
+    }
+}
+
+/// An element of `GF(MODULUS)` kept in Montgomery representation.
+///
+/// Exposes the same operator surface as [`FieldElement`], so the two can be
+/// swapped in benchmarks and generic code. `MODULUS` must be odd.
+#[derive(Debug, Clone, Copy)]
+pub struct MontFieldElement<const MODULUS: u64> {
+    /// `a * R mod MODULUS`.
+    mont: u64,
+}
+
+impl<const MODULUS: u64> MontFieldElement<MODULUS> {
+    const ODD_MODULUS: () = assert!(MODULUS % 2 == 1, "Montgomery form needs an odd modulus");
+
+    /// `-MODULUS^{-1} mod 2^64`.
+    pub const NEG_INV: u64 = mont_neg_inv(MODULUS);
+    /// `2^64 mod MODULUS`, the Montgomery form of one.
+    pub const R: u64 = mont_r(MODULUS);
+    /// `2^128 mod MODULUS`, used to convert into Montgomery form.
+    pub const R2: u64 = mont_r2(MODULUS);
+
+    pub fn new(value: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::ODD_MODULUS;
+        Self::from_canonical(value % MODULUS)
+    }
+
+    #[inline(always)]
+    fn from_canonical(value: u64) -> Self {
+        MontFieldElement {
+            mont: mont_redc(value as u128 * Self::R2 as u128, MODULUS, Self::NEG_INV),
+        }
+    }
+
+    pub fn zero() -> Self {
+        MontFieldElement { mont: 0 }
+    }
+
+    pub fn one() -> Self {
+        MontFieldElement { mont: Self::R }
+    }
+
+    /// The canonical value in `[0, MODULUS)`.
+    pub fn value(&self) -> u64 {
+        mont_redc(self.mont as u128, MODULUS, Self::NEG_INV)
+    }
+
+    /// The raw Montgomery representation `a * 2^64 mod MODULUS`.
+    pub fn montgomery_value(&self) -> u64 {
+        self.mont
+    }
+
+    pub fn random() -> Self {
+        Self::random_with(&mut OsRng)
+    }
+
+    /// Uniform sample, rejection-sampled like [`FieldElement::random_with`].
+    pub fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        MontFieldElement::from(FieldElement::<MODULUS>::random_with(rng))
+    }
+
+    /// Square-and-multiply, staying in Montgomery form throughout.
+    pub fn pow(&self, exp: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = *self;
+        let mut e = exp;
+
+        while e > 0 {
+            if e & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        result
+    }
+
+    /// `a^(p-2) % p`.
+    pub fn inverse(&self) -> Self {
+        assert!(MODULUS > 2, "Modulus must be > 2 for inverse calculation");
+        self.pow(MODULUS - 2)
+    }
+
+    pub fn to_bytes(&self) -> [u8; 8] {
+        self.value().to_be_bytes() //big endian, canonical value
+    }
+}
+
+impl<const MODULUS: u64> PartialEq for MontFieldElement<MODULUS> {
+    fn eq(&self, other: &Self) -> bool {
+        // The Montgomery map is a bijection, so comparing representations is enough.
+        self.mont.ct_eq(&other.mont).unwrap_u8() == 1
+    }
+}
+
+impl<const MODULUS: u64> Eq for MontFieldElement<MODULUS> {}
+
+impl<const MODULUS: u64> Hash for MontFieldElement<MODULUS> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.mont.hash(state);
+    }
+}
+
+impl<const MODULUS: u64> Add for MontFieldElement<MODULUS> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (sum, carry) = self.mont.overflowing_add(rhs.mont);
+        let mont = if carry || sum >= MODULUS {
+            sum.wrapping_sub(MODULUS)
+        } else {
+            sum
+        };
+        MontFieldElement { mont }
+    }
+}
+
+impl<const MODULUS: u64> AddAssign for MontFieldElement<MODULUS> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const MODULUS: u64> Sub for MontFieldElement<MODULUS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (diff, borrow) = self.mont.overflowing_sub(rhs.mont);
+        let mont = if borrow { diff.wrapping_add(MODULUS) } else { diff };
+        MontFieldElement { mont }
+    }
+}
+
+impl<const MODULUS: u64> SubAssign for MontFieldElement<MODULUS> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const MODULUS: u64> Mul for MontFieldElement<MODULUS> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        MontFieldElement {
+            mont: mont_redc(self.mont as u128 * rhs.mont as u128, MODULUS, Self::NEG_INV),
+        }
+    }
+}
+
+impl<const MODULUS: u64> MulAssign for MontFieldElement<MODULUS> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const MODULUS: u64> Div for MontFieldElement<MODULUS> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+impl<const MODULUS: u64> DivAssign for MontFieldElement<MODULUS> {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self *= rhs.inverse();
+    }
+}
+
+impl<const MODULUS: u64> Neg for MontFieldElement<MODULUS> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        MontFieldElement::zero() - self
+    }
+}
+
+impl<const M: u64> From<i128> for MontFieldElement<M> {
+    fn from(value: i128) -> Self {
+        MontFieldElement::from(FieldElement::<M>::from(value))
+    }
+}
+
+impl<const M: u64> From<FieldElement<M>> for MontFieldElement<M> {
+    fn from(value: FieldElement<M>) -> Self {
+        MontFieldElement::from_canonical(value.value())
+    }
+}
+
+impl<const M: u64> From<MontFieldElement<M>> for FieldElement<M> {
+    fn from(value: MontFieldElement<M>) -> Self {
+        FieldElement::new(value.value())
+    }
+}
+
+impl<const M: u64> Distribution<MontFieldElement<M>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> MontFieldElement<M> {
+        MontFieldElement::random_with(rng)
+    }
+}
+
+impl<const M: u64> Field for MontFieldElement<M> {
+    fn zero() -> Self {
+        MontFieldElement::zero()
+    }
+
+    fn one() -> Self {
+        MontFieldElement::one()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        MontFieldElement::new(value)
+    }
+
+    fn inverse(&self) -> Self {
+        MontFieldElement::inverse(self)
+    }
+
+    fn pow(&self, exp: u64) -> Self {
+        MontFieldElement::pow(self, exp)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        MontFieldElement::to_bytes(self).to_vec()
+    }
+
+    /// Same encoding as `FieldElement`: 8 big-endian bytes of the canonical value.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FieldError> {
+        <FieldElement<M> as Field>::from_bytes(bytes).map(MontFieldElement::from)
+    }
+
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        MontFieldElement::random_with(rng)
+    }
+
+    fn characteristic() -> U256 {
+        U256::from(M)
+    }
+
+    fn two_adicity() -> u32 {
+        (M - 1).trailing_zeros()
+    }
+
+    fn two_adic_root_of_unity(log_n: u32) -> Option<Self> {
+        params::two_adic_root_of_unity(M, log_n).map(MontFieldElement::new)
+    }
+
+    fn multiplicative_generator() -> Option<Self> {
+        params::field_params(M).ok().map(|params| MontFieldElement::new(params.generator))
+    }
+}
+
+#[cfg(test)]
+mod test_montgomery {
+    use super::*;
+    use crate::polynomial::Polynomial;
+
+    const P: u64 = 2147483647;
+    type Mont = MontFieldElement<P>;
+    type Plain = FieldElement<P>;
+
+    #[test]
+    fn test_round_trip() {
+        for v in [0u64, 1, 2, 12345, P - 1, P, P + 5] {
+            assert_eq!(Mont::new(v).value(), v % P);
+        }
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(P.wrapping_mul(Mont::NEG_INV), u64::MAX);
+        assert_eq!(Mont::one().value(), 1);
+        assert_eq!(MontFieldElement::<7>::R, ((1u128 << 64) % 7) as u64);
+    }
+
+    #[test]
+    fn test_const_helpers() {
+        assert_eq!(const_pow_mod(3, 16, 17), 1);
+        assert_eq!(const_pow_mod(5, 0, 1), 0);
+        for n in [2u64, 3, 17, P, 18446744073709551557, 0xffff_ffff_0000_0001] {
+            assert!(const_is_prime(n), "{n}");
+        }
+        // 3215031751 = 151 * 751 * 28351 is a strong pseudoprime to bases 2, 3, 5 and 7.
+        for n in [0u64, 1, 15, 561, 3215031751, u64::MAX] {
+            assert!(!const_is_prime(n), "{n}");
+        }
+    }
+
+    #[test]
+    fn test_matches_plain_arithmetic() {
+        let pairs = [(3u64, 5u64), (P - 1, P - 2), (123456789, 987654321), (0, 42)];
+        for (x, y) in pairs {
+            let (a, b) = (Mont::new(x), Mont::new(y));
+            let (c, d) = (Plain::new(x), Plain::new(y));
+            assert_eq!((a + b).value(), (c + d).value());
+            assert_eq!((a - b).value(), (c - d).value());
+            assert_eq!((a * b).value(), (c * d).value());
+            assert_eq!((-a).value(), (-c).value());
+            assert_eq!(a.pow(77).value(), c.pow(77).value());
+        }
+    }
+
+    #[test]
+    fn test_small_modulus() {
+        let a = MontFieldElement::<7>::new(3);
+        let b = MontFieldElement::<7>::new(5);
+        assert_eq!((a * b).value(), 1);
+        assert_eq!((a / b).value(), 2);
+        assert_eq!(a.inverse().value(), 5);
+        assert_eq!((a - b).value(), 5);
+    }
+
+    #[test]
+    fn test_large_modulus() {
+        // Largest 64-bit prime: exercises the carry in REDC and in addition.
+        const Q: u64 = 18446744073709551557;
+        let a = MontFieldElement::<Q>::new(Q - 1);
+        let b = MontFieldElement::<Q>::new(Q - 2);
+        let expected = ((Q - 1) as u128 * (Q - 2) as u128 % Q as u128) as u64;
+        assert_eq!((a * b).value(), expected);
+        assert_eq!((a + b).value(), Q - 3);
+        assert_eq!((a * a.inverse()).value(), 1);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let a = Mont::new(99);
+        let plain: Plain = a.into();
+        assert_eq!(plain.value(), 99);
+        assert_eq!(Mont::from(plain), a);
+        assert_eq!(Mont::from(-1i128).value(), P - 1);
+    }
+
+    #[test]
+    fn test_polynomial_over_montgomery() {
+        let p = Polynomial::new(vec![Mont::new(1), Mont::new(2)]);
+        let q = Polynomial::new(vec![Mont::new(3), Mont::new(4)]);
+        let r = &p * &q;
+        assert_eq!(r.evaluate(Mont::new(5)), p.evaluate(Mont::new(5)) * q.evaluate(Mont::new(5)));
+    }
+}
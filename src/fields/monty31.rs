@@ -0,0 +1,393 @@
+//! 31-bit prime fields in Montgomery form with `R = 2^32`.
+//!
+//! Every product of two reduced values fits in a `u64` and is brought back with
+//! a single 32-bit REDC, so no `u128` arithmetic is involved. BabyBear and
+//! KoalaBear are the two instances used by other STARK stacks.
+
+use crate::fields::{params, Field, FieldElement, FieldError};
+use alloy::primitives::U256;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use rand_core::{OsRng, RngCore};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use subtle::ConstantTimeEq;
+
+/// BabyBear: `15 * 2^27 + 1`.
+pub type BabyBear = Monty31<0x7800_0001>;
+
+/// KoalaBear: `2^31 - 2^24 + 1`.
+pub type KoalaBear = Monty31<0x7f00_0001>;
+
+/// `-p^{-1} mod 2^32` by Newton iteration.
+pub(crate) const fn neg_inv_32(p: u32) -> u32 {
+    let mut inv = 1u32;
+    let mut i = 0;
+    while i < 5 {
+        inv = inv.wrapping_mul(2u32.wrapping_sub(p.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+/// An element of `GF(P)` for an odd prime `P < 2^31`, stored as `a * 2^32 mod P`.
+#[derive(Debug, Clone, Copy)]
+pub struct Monty31<const P: u32> {
+    mont: u32,
+}
+
+impl<const P: u32> Monty31<P> {
+    const VALID_MODULUS: () = assert!(P % 2 == 1 && P < (1 << 31), "Monty31 needs an odd modulus below 2^31");
+
+    pub const MODULUS: u32 = P;
+    /// `-P^{-1} mod 2^32`.
+    const NEG_INV: u32 = neg_inv_32(P);
+    /// `2^32 mod P`, the Montgomery form of one.
+    const R: u32 = ((1u64 << 32) % P as u64) as u32;
+    /// `2^64 mod P`.
+    const R2: u32 = ((Self::R as u64 * Self::R as u64) % P as u64) as u32;
+
+    pub fn new(value: u32) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID_MODULUS;
+        Monty31 {
+            mont: Self::redc((value % P) as u64 * Self::R2 as u64),
+        }
+    }
+
+    pub fn zero() -> Self {
+        Monty31 { mont: 0 }
+    }
+
+    pub fn one() -> Self {
+        Monty31 { mont: Self::R }
+    }
+
+    /// The canonical value in `[0, P)`.
+    pub fn value(&self) -> u32 {
+        Self::redc(self.mont as u64)
+    }
+
+    pub fn random() -> Self {
+        Self::random_with(&mut OsRng)
+    }
+
+    /// Uniform sample by rejection against the largest multiple of `P` below `2^32`.
+    pub fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        let zone = (1u64 << 32) - (1u64 << 32) % P as u64;
+        loop {
+            let x = rng.next_u32();
+            if (x as u64) < zone {
+                return Monty31::new(x % P);
+            }
+        }
+    }
+
+    pub fn pow(&self, exp: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = *self;
+        let mut e = exp;
+
+        while e > 0 {
+            if e & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        result
+    }
+
+    /// `a^(p-2) % p`.
+    pub fn inverse(&self) -> Self {
+        self.pow(P as u64 - 2)
+    }
+
+    pub fn to_bytes(&self) -> [u8; 4] {
+        self.value().to_be_bytes() //big endian
+    }
+
+    /// `x * 2^{-32} mod P` for `x < P * 2^32`.
+    #[inline(always)]
+    fn redc(x: u64) -> u32 {
+        let m = (x as u32).wrapping_mul(Self::NEG_INV);
+        // x < P^2 < 2^62 and m * P < 2^63, so the sum cannot overflow.
+        let t = ((x + m as u64 * P as u64) >> 32) as u32;
+        if t >= P {
+            t - P
+        } else {
+            t
+        }
+    }
+}
+
+impl<const P: u32> PartialEq for Monty31<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.mont.ct_eq(&other.mont).unwrap_u8() == 1
+    }
+}
+
+impl<const P: u32> Eq for Monty31<P> {}
+
+impl<const P: u32> Hash for Monty31<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.mont.hash(state);
+    }
+}
+
+impl<const P: u32> Add for Monty31<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // P < 2^31, so the sum fits in u32.
+        let sum = self.mont + rhs.mont;
+        Monty31 {
+            mont: if sum >= P { sum - P } else { sum },
+        }
+    }
+}
+
+impl<const P: u32> AddAssign for Monty31<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u32> Sub for Monty31<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (diff, borrow) = self.mont.overflowing_sub(rhs.mont);
+        Monty31 {
+            mont: if borrow { diff.wrapping_add(P) } else { diff },
+        }
+    }
+}
+
+impl<const P: u32> SubAssign for Monty31<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u32> Mul for Monty31<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Monty31 {
+            mont: Self::redc(self.mont as u64 * rhs.mont as u64),
+        }
+    }
+}
+
+impl<const P: u32> MulAssign for Monty31<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u32> Div for Monty31<P> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+impl<const P: u32> DivAssign for Monty31<P> {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self *= rhs.inverse();
+    }
+}
+
+impl<const P: u32> Neg for Monty31<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Monty31::zero() - self
+    }
+}
+
+impl<const P: u32> From<i128> for Monty31<P> {
+    fn from(value: i128) -> Self {
+        let modulus = P as i128;
+        let mut val = value % modulus;
+        if val < 0 {
+            val += modulus; // ensure in [0, P-1]
+        }
+        Monty31::new(val as u32)
+    }
+}
+
+impl<const P: u32> Distribution<Monty31<P>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Monty31<P> {
+        Monty31::random_with(rng)
+    }
+}
+
+impl<const P: u32> Field for Monty31<P> {
+    fn zero() -> Self {
+        Monty31::zero()
+    }
+
+    fn one() -> Self {
+        Monty31::one()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Monty31::new((value % P as u64) as u32)
+    }
+
+    fn inverse(&self) -> Self {
+        Monty31::inverse(self)
+    }
+
+    fn pow(&self, exp: u64) -> Self {
+        Monty31::pow(self, exp)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        Monty31::to_bytes(self).to_vec()
+    }
+
+    /// Expects exactly 4 big-endian bytes holding a value below `P`.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FieldError> {
+        let raw: [u8; 4] = bytes.try_into().map_err(|_| FieldError::InvalidLength {
+            expected: 4,
+            actual: bytes.len(),
+        })?;
+        let value = u32::from_be_bytes(raw);
+        if value >= P {
+            return Err(FieldError::NonCanonical {
+                value: value as u64,
+                modulus: P as u64,
+            });
+        }
+        Ok(Monty31::new(value))
+    }
+
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Monty31::random_with(rng)
+    }
+
+    fn characteristic() -> U256 {
+        U256::from(P)
+    }
+
+    fn two_adicity() -> u32 {
+        (P - 1).trailing_zeros()
+    }
+
+    fn two_adic_root_of_unity(log_n: u32) -> Option<Self> {
+        params::two_adic_root_of_unity(P as u64, log_n).map(|root| Monty31::new(root as u32))
+    }
+
+    fn multiplicative_generator() -> Option<Self> {
+        params::field_params(P as u64)
+            .ok()
+            .map(|params| Monty31::new(params.generator as u32))
+    }
+}
+
+impl From<BabyBear> for FieldElement<{ BabyBear::MODULUS as u64 }> {
+    fn from(value: BabyBear) -> Self {
+        FieldElement::new(value.value() as u64)
+    }
+}
+
+impl From<KoalaBear> for FieldElement<{ KoalaBear::MODULUS as u64 }> {
+    fn from(value: KoalaBear) -> Self {
+        FieldElement::new(value.value() as u64)
+    }
+}
+
+#[cfg(test)]
+mod test_monty31 {
+    use super::*;
+    use crate::fri::CosetFri;
+    use crate::polynomial::Polynomial;
+    use rand::{RngCore, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+
+    fn check_against_reference<const P: u32, const M: u64>() {
+        let mut rng = ChaCha20Rng::seed_from_u64(P as u64);
+        for _ in 0..1000 {
+            let (x, y) = (rng.next_u32() % P, rng.next_u32() % P);
+            let (a, b) = (Monty31::<P>::new(x), Monty31::<P>::new(y));
+            let (c, d) = (FieldElement::<M>::new(x as u64), FieldElement::<M>::new(y as u64));
+            assert_eq!((a + b).value() as u64, (c + d).value());
+            assert_eq!((a - b).value() as u64, (c - d).value());
+            assert_eq!((a * b).value() as u64, (c * d).value());
+            assert_eq!((-a).value() as u64, (-c).value());
+        }
+    }
+
+    #[test]
+    fn test_baby_bear_matches_reference() {
+        check_against_reference::<0x7800_0001, 0x7800_0001>();
+    }
+
+    #[test]
+    fn test_koala_bear_matches_reference() {
+        check_against_reference::<0x7f00_0001, 0x7f00_0001>();
+    }
+
+    #[test]
+    fn test_round_trip_and_edges() {
+        let p = BabyBear::MODULUS;
+        assert_eq!(BabyBear::new(p).value(), 0);
+        assert_eq!(BabyBear::new(p - 1).value(), p - 1);
+        assert_eq!(BabyBear::one().value(), 1);
+        let minus_one = BabyBear::new(p - 1);
+        assert_eq!(minus_one * minus_one, BabyBear::one());
+        assert_eq!(BabyBear::from(-1i128), minus_one);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = KoalaBear::new(123456);
+        assert_eq!(a * a.inverse(), KoalaBear::one());
+        assert_eq!((a / a).value(), 1);
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let a = BabyBear::new(0x1234_5678);
+        let bytes = Field::to_bytes(&a);
+        assert_eq!(bytes.len(), 4);
+        assert_eq!(<BabyBear as Field>::from_bytes(&bytes), Ok(a));
+        assert_eq!(
+            <BabyBear as Field>::from_bytes(&BabyBear::MODULUS.to_be_bytes()),
+            Err(FieldError::NonCanonical {
+                value: BabyBear::MODULUS as u64,
+                modulus: BabyBear::MODULUS as u64
+            })
+        );
+    }
+
+    #[test]
+    fn test_polynomial_over_baby_bear() {
+        let p = Polynomial::new(vec![BabyBear::new(1), BabyBear::new(2), BabyBear::new(3)]);
+        let q = Polynomial::new(vec![BabyBear::new(5), BabyBear::new(1)]);
+        let (quot, rem) = (&p * &q).div_rem(&q);
+        assert_eq!(quot, p);
+        assert!(rem.is_zero());
+    }
+
+    #[test]
+    fn test_coset_fri_over_baby_bear() {
+        // 31 generates BabyBear^*, so 31^((p-1)/8) has order 8.
+        let omega = BabyBear::new(31).pow((BabyBear::MODULUS as u64 - 1) / 8);
+        let fri = CosetFri::new(BabyBear::new(31), omega, 8).unwrap();
+        let domain = fri.generate_coset_domain();
+        assert_eq!(domain.len(), 8);
+        assert_eq!(omega.pow(8), BabyBear::one());
+        assert_ne!(omega.pow(4), BabyBear::one());
+        // Squaring a coset of size 8 yields a coset of size 4, listed twice.
+        let next = fri.next_coset_domain(&domain);
+        assert_eq!(next[0], next[4]);
+        assert_eq!(next[0], BabyBear::new(31).pow(2));
+    }
+}
@@ -0,0 +1,443 @@
+//! Lane-parallel arithmetic over slices of `FieldElement<M>`.
+//!
+//! The kernels pick the widest instruction set detected at runtime:
+//! - add / sub use AVX-512F (8 lanes) or AVX2 (4 lanes) when `M < 2^63`, so a
+//!   sum or difference of two reduced values never leaves the signed range;
+//! - mul uses a 32-bit Montgomery product on the same registers when
+//!   `M < 2^31`, where every intermediate fits in a 64-bit lane.
+//!
+//! Everything else, and every other target, falls back to the scalar
+//! operators. Results are always identical to the scalar ones.
+
+use crate::fields::monty31::neg_inv_32;
+use crate::fields::FieldElement;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// Number of lanes in a [`PackedFieldElement`].
+pub const WIDTH: usize = 8;
+
+/// `WIDTH` field elements operated on together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(C, align(64))]
+pub struct PackedFieldElement<const M: u64>(pub [FieldElement<M>; WIDTH]);
+
+impl<const M: u64> PackedFieldElement<M> {
+    pub fn new(lanes: [FieldElement<M>; WIDTH]) -> Self {
+        PackedFieldElement(lanes)
+    }
+
+    /// Every lane set to `value`.
+    pub fn broadcast(value: FieldElement<M>) -> Self {
+        PackedFieldElement([value; WIDTH])
+    }
+
+    /// The first `WIDTH` elements of `values`. Panics if there are fewer.
+    pub fn from_slice(values: &[FieldElement<M>]) -> Self {
+        let lanes: [FieldElement<M>; WIDTH] = values[..WIDTH].try_into().unwrap();
+        PackedFieldElement(lanes)
+    }
+
+    pub fn lanes(&self) -> &[FieldElement<M>; WIDTH] {
+        &self.0
+    }
+}
+
+impl<const M: u64> AddAssign for PackedFieldElement<M> {
+    fn add_assign(&mut self, rhs: Self) {
+        add_assign_slices(&mut self.0, &rhs.0);
+    }
+}
+
+impl<const M: u64> Add for PackedFieldElement<M> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const M: u64> SubAssign for PackedFieldElement<M> {
+    fn sub_assign(&mut self, rhs: Self) {
+        sub_assign_slices(&mut self.0, &rhs.0);
+    }
+}
+
+impl<const M: u64> Sub for PackedFieldElement<M> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const M: u64> MulAssign for PackedFieldElement<M> {
+    fn mul_assign(&mut self, rhs: Self) {
+        mul_assign_slices(&mut self.0, &rhs.0);
+    }
+}
+
+impl<const M: u64> Mul for PackedFieldElement<M> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: Self) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+/// The instruction set a kernel runs on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    Scalar,
+    Avx2,
+    Avx512,
+}
+
+impl Backend {
+    /// The widest backend usable on this CPU.
+    pub fn detect() -> Backend {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if is_x86_feature_detected!("avx512f") {
+                return Backend::Avx512;
+            }
+            if is_x86_feature_detected!("avx2") {
+                return Backend::Avx2;
+            }
+        }
+        Backend::Scalar
+    }
+
+    /// Whether this backend can run on the current CPU.
+    pub fn is_available(self) -> bool {
+        match self {
+            Backend::Scalar => true,
+            Backend::Avx2 => matches!(Self::detect(), Backend::Avx2 | Backend::Avx512),
+            Backend::Avx512 => Self::detect() == Backend::Avx512,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl Op {
+    /// Whether the SIMD kernels handle this modulus.
+    fn vectorizable(self, m: u64) -> bool {
+        match self {
+            Op::Add | Op::Sub => m < 1 << 63,
+            // Montgomery reduction needs an odd modulus.
+            Op::Mul => m < 1 << 31 && m % 2 == 1,
+        }
+    }
+
+    fn scalar<const M: u64>(self, a: &mut FieldElement<M>, b: FieldElement<M>) {
+        match self {
+            Op::Add => *a += b,
+            Op::Sub => *a -= b,
+            Op::Mul => *a *= b,
+        }
+    }
+}
+
+fn as_u64s<const M: u64>(values: &[FieldElement<M>]) -> &[u64] {
+    // SAFETY: FieldElement is repr(transparent) over u64.
+    unsafe { std::slice::from_raw_parts(values.as_ptr() as *const u64, values.len()) }
+}
+
+fn as_u64s_mut<const M: u64>(values: &mut [FieldElement<M>]) -> &mut [u64] {
+    // SAFETY: FieldElement is repr(transparent) over u64, and every kernel
+    // writes back values reduced below M.
+    unsafe { std::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut u64, values.len()) }
+}
+
+/// `lhs[i] op= rhs[i]`, or `lhs[i] op= scalar` when `rhs` is `None`.
+fn apply<const M: u64>(
+    backend: Backend,
+    op: Op,
+    lhs: &mut [FieldElement<M>],
+    rhs: Option<&[FieldElement<M>]>,
+    scalar: FieldElement<M>,
+) {
+    if let Some(rhs) = rhs {
+        assert_eq!(lhs.len(), rhs.len(), "slice lengths differ");
+    }
+    let done = if op.vectorizable(M) {
+        let rhs_raw = rhs.map(as_u64s);
+        let lhs_raw = as_u64s_mut(lhs);
+        match backend {
+            #[cfg(target_arch = "x86_64")]
+            // SAFETY: callers only pass a SIMD backend that `is_available`.
+            Backend::Avx512 => unsafe { x86::avx512(op, lhs_raw, rhs_raw, scalar.value(), M) },
+            #[cfg(target_arch = "x86_64")]
+            Backend::Avx2 => unsafe { x86::avx2(op, lhs_raw, rhs_raw, scalar.value(), M) },
+            _ => 0,
+        }
+    } else {
+        0
+    };
+    match rhs {
+        Some(rhs) => {
+            for (a, b) in lhs[done..].iter_mut().zip(&rhs[done..]) {
+                op.scalar(a, *b);
+            }
+        }
+        None => {
+            for a in lhs[done..].iter_mut() {
+                op.scalar(a, scalar);
+            }
+        }
+    }
+}
+
+/// `lhs[i] += rhs[i]`. Panics if the lengths differ.
+pub fn add_assign_slices<const M: u64>(lhs: &mut [FieldElement<M>], rhs: &[FieldElement<M>]) {
+    apply(Backend::detect(), Op::Add, lhs, Some(rhs), FieldElement::zero());
+}
+
+/// `lhs[i] -= rhs[i]`. Panics if the lengths differ.
+pub fn sub_assign_slices<const M: u64>(lhs: &mut [FieldElement<M>], rhs: &[FieldElement<M>]) {
+    apply(Backend::detect(), Op::Sub, lhs, Some(rhs), FieldElement::zero());
+}
+
+/// `lhs[i] *= rhs[i]`. Panics if the lengths differ.
+pub fn mul_assign_slices<const M: u64>(lhs: &mut [FieldElement<M>], rhs: &[FieldElement<M>]) {
+    apply(Backend::detect(), Op::Mul, lhs, Some(rhs), FieldElement::zero());
+}
+
+/// `values[i] *= scalar`.
+pub fn scale_slice<const M: u64>(values: &mut [FieldElement<M>], scalar: FieldElement<M>) {
+    apply(Backend::detect(), Op::Mul, values, None, scalar);
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use super::{neg_inv_32, Op};
+    use std::arch::x86_64::*;
+
+    /// `2^64 mod m`, which turns a Montgomery product back into a plain one.
+    fn r2(m: u64) -> u64 {
+        ((1u128 << 64) % m as u128) as u64
+    }
+
+    /// Runs `op` over the longest prefix that fills whole AVX2 registers and
+    /// returns its length. Requires `m < 2^63` (and `m < 2^31` for `Op::Mul`).
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn avx2(op: Op, lhs: &mut [u64], rhs: Option<&[u64]>, scalar: u64, m: u64) -> usize {
+        let n = lhs.len() / 4 * 4;
+        let mv = _mm256_set1_epi64x(m as i64);
+        let (ninv, r2v) = if let Op::Mul = op {
+            (
+                _mm256_set1_epi64x(neg_inv_32(m as u32) as i64),
+                _mm256_set1_epi64x(r2(m) as i64),
+            )
+        } else {
+            (_mm256_setzero_si256(), _mm256_setzero_si256())
+        };
+        let sv = _mm256_set1_epi64x(scalar as i64);
+
+        for i in (0..n).step_by(4) {
+            let pa = lhs.as_mut_ptr().add(i) as *mut __m256i;
+            let a = _mm256_loadu_si256(pa);
+            let b = match rhs {
+                Some(rhs) => _mm256_loadu_si256(rhs.as_ptr().add(i) as *const __m256i),
+                None => sv,
+            };
+            let r = match op {
+                Op::Add => {
+                    let sum = _mm256_add_epi64(a, b);
+                    reduce_once_avx2(sum, mv)
+                }
+                Op::Sub => {
+                    // |a - b| < 2^63, so the sign bit of the difference is the borrow.
+                    let diff = _mm256_sub_epi64(a, b);
+                    select_on_sign_avx2(diff, _mm256_add_epi64(diff, mv), diff)
+                }
+                Op::Mul => {
+                    let ab_over_r = mont_mul_avx2(a, b, mv, ninv);
+                    mont_mul_avx2(ab_over_r, r2v, mv, ninv)
+                }
+            };
+            _mm256_storeu_si256(pa, r);
+        }
+        n
+    }
+
+    /// `if sign(mask) { yes } else { no }` lane by lane.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn select_on_sign_avx2(no: __m256i, yes: __m256i, mask: __m256i) -> __m256i {
+        _mm256_castpd_si256(_mm256_blendv_pd(
+            _mm256_castsi256_pd(no),
+            _mm256_castsi256_pd(yes),
+            _mm256_castsi256_pd(mask),
+        ))
+    }
+
+    /// Maps `x < 2m` to `x mod m`: `x - m` is negative exactly when `x < m`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn reduce_once_avx2(x: __m256i, mv: __m256i) -> __m256i {
+        let t = _mm256_sub_epi64(x, mv);
+        select_on_sign_avx2(t, x, t)
+    }
+
+    /// `a * b * 2^-32 mod m` for `a, b < m < 2^31`.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn mont_mul_avx2(a: __m256i, b: __m256i, mv: __m256i, ninv: __m256i) -> __m256i {
+        // t < m^2 < 2^62; q = (t mod 2^32) * (-m^-1) keeps its low 32 bits for
+        // the next 32x32 product, and t + q*m < 2^62 + 2^63 cannot overflow.
+        let t = _mm256_mul_epu32(a, b);
+        let q = _mm256_mul_epu32(t, ninv);
+        let u = _mm256_srli_epi64(_mm256_add_epi64(t, _mm256_mul_epu32(q, mv)), 32);
+        reduce_once_avx2(u, mv)
+    }
+
+    /// The AVX-512F counterpart of [`avx2`], eight lanes at a time.
+    #[target_feature(enable = "avx512f")]
+    pub(super) unsafe fn avx512(op: Op, lhs: &mut [u64], rhs: Option<&[u64]>, scalar: u64, m: u64) -> usize {
+        let n = lhs.len() / 8 * 8;
+        let mv = _mm512_set1_epi64(m as i64);
+        let (ninv, r2v) = if let Op::Mul = op {
+            (
+                _mm512_set1_epi64(neg_inv_32(m as u32) as i64),
+                _mm512_set1_epi64(r2(m) as i64),
+            )
+        } else {
+            (_mm512_setzero_si512(), _mm512_setzero_si512())
+        };
+        let sv = _mm512_set1_epi64(scalar as i64);
+
+        for i in (0..n).step_by(8) {
+            let pa = lhs.as_mut_ptr().add(i) as *mut __m512i;
+            let a = _mm512_loadu_si512(pa);
+            let b = match rhs {
+                Some(rhs) => _mm512_loadu_si512(rhs.as_ptr().add(i) as *const __m512i),
+                None => sv,
+            };
+            let r = match op {
+                Op::Add => {
+                    let sum = _mm512_add_epi64(a, b);
+                    // sum - m wraps past sum exactly when sum < m.
+                    _mm512_min_epu64(sum, _mm512_sub_epi64(sum, mv))
+                }
+                Op::Sub => {
+                    let diff = _mm512_sub_epi64(a, b);
+                    _mm512_min_epu64(diff, _mm512_add_epi64(diff, mv))
+                }
+                Op::Mul => {
+                    let ab_over_r = mont_mul_avx512(a, b, mv, ninv);
+                    mont_mul_avx512(ab_over_r, r2v, mv, ninv)
+                }
+            };
+            _mm512_storeu_si512(pa, r);
+        }
+        n
+    }
+
+    #[inline]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn mont_mul_avx512(a: __m512i, b: __m512i, mv: __m512i, ninv: __m512i) -> __m512i {
+        let t = _mm512_mul_epu32(a, b);
+        let q = _mm512_mul_epu32(t, ninv);
+        let u = _mm512_srli_epi64(_mm512_add_epi64(t, _mm512_mul_epu32(q, mv)), 32);
+        _mm512_min_epu64(u, _mm512_sub_epi64(u, mv))
+    }
+}
+
+#[cfg(test)]
+mod test_packed {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    fn random_vec<const M: u64>(n: usize, rng: &mut ChaCha20Rng) -> Vec<FieldElement<M>> {
+        (0..n).map(|_| FieldElement::random_with(rng)).collect()
+    }
+
+    /// Every available backend agrees with the scalar operators, including on
+    /// lengths that leave a scalar tail.
+    fn check_backends<const M: u64>() {
+        let mut rng = ChaCha20Rng::seed_from_u64(M);
+        for backend in [Backend::Scalar, Backend::Avx2, Backend::Avx512] {
+            if !backend.is_available() {
+                continue;
+            }
+            for n in [0, 1, 3, 4, 8, 13, 64, 101] {
+                let a = random_vec::<M>(n, &mut rng);
+                let b = random_vec::<M>(n, &mut rng);
+                let s = FieldElement::<M>::random_with(&mut rng);
+                for (op, expected) in [
+                    (Op::Add, a.iter().zip(&b).map(|(x, y)| *x + *y).collect::<Vec<_>>()),
+                    (Op::Sub, a.iter().zip(&b).map(|(x, y)| *x - *y).collect()),
+                    (Op::Mul, a.iter().zip(&b).map(|(x, y)| *x * *y).collect()),
+                ] {
+                    let mut out = a.clone();
+                    apply(backend, op, &mut out, Some(&b), FieldElement::zero());
+                    assert_eq!(out, expected, "{:?}, n = {}", backend, n);
+                }
+                let mut scaled = a.clone();
+                apply(backend, Op::Mul, &mut scaled, None, s);
+                assert_eq!(scaled, a.iter().map(|x| *x * s).collect::<Vec<_>>());
+            }
+        }
+    }
+
+    #[test]
+    fn test_backends_small_modulus() {
+        check_backends::<17>();
+    }
+
+    #[test]
+    fn test_backends_31_bit() {
+        check_backends::<0x7800_0001>(); // BabyBear
+        check_backends::<0x7fff_ffff>(); // Mersenne31
+    }
+
+    #[test]
+    fn test_backends_large_modulus() {
+        // SIMD add/sub, scalar mul.
+        check_backends::<0x7fff_ffff_ffff_ffe7>(); // largest prime below 2^63
+        // Scalar everything.
+        check_backends::<0xFFFF_FFFF_0000_0001>(); // Goldilocks
+    }
+
+    #[test]
+    fn test_edge_values() {
+        const P: u64 = 0x7800_0001;
+        let max = FieldElement::<P>::new(P - 1);
+        let mut a = vec![max; 16];
+        add_assign_slices(&mut a, &[max; 16]);
+        assert!(a.iter().all(|x| x.value() == P - 2));
+        let mut b = vec![FieldElement::<P>::zero(); 16];
+        sub_assign_slices(&mut b, &[FieldElement::one(); 16]);
+        assert!(b.iter().all(|x| x.value() == P - 1));
+        mul_assign_slices(&mut b, &[max; 16]);
+        assert!(b.iter().all(|x| x.value() == 1));
+    }
+
+    #[test]
+    fn test_packed_element_ops() {
+        let a = PackedFieldElement::<17>::new(std::array::from_fn(|i| FieldElement::new(i as u64)));
+        let b = PackedFieldElement::broadcast(FieldElement::<17>::new(5));
+        assert_eq!((a + b).lanes()[7], FieldElement::new(12));
+        assert_eq!((a - b).lanes()[0], FieldElement::new(12));
+        assert_eq!((a * b).lanes()[4], FieldElement::new(3));
+        let values: Vec<_> = (0..10).map(FieldElement::<17>::new).collect();
+        assert_eq!(PackedFieldElement::from_slice(&values[2..]).lanes()[0], FieldElement::new(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "slice lengths differ")]
+    fn test_length_mismatch_panics() {
+        let mut a = vec![FieldElement::<17>::one(); 3];
+        add_assign_slices(&mut a, &[FieldElement::one(); 2]);
+    }
+}
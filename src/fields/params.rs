@@ -0,0 +1,183 @@
+//! Multiplicative-group parameters of `GF(p)` for a 64-bit prime `p`.
+//!
+//! Factoring `p - 1` is the expensive part of finding a generator, so the
+//! result is computed once per modulus and cached for the life of the process.
+
+use crate::fields::{FieldElement, FieldError};
+use num_prime::nt_funcs::{factorize64, is_prime64};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Parameters of the multiplicative group `GF(p)^*` of order `p - 1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldParams {
+    pub modulus: u64,
+    /// Prime factorisation of `p - 1` as `(prime, exponent)`, smallest prime first.
+    pub group_order_factors: Vec<(u64, usize)>,
+    /// The smallest generator of `GF(p)^*`.
+    pub generator: u64,
+    /// Largest `k` with `2^k | p - 1`.
+    pub two_adicity: u32,
+}
+
+fn cache() -> &'static Mutex<HashMap<u64, Arc<FieldParams>>> {
+    static CACHE: OnceLock<Mutex<HashMap<u64, Arc<FieldParams>>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn pow_mod(base: u64, exp: u64, modulus: u64) -> u64 {
+    let m = modulus as u128;
+    let mut result = 1u128 % m;
+    let mut base = base as u128 % m;
+    let mut e = exp;
+    while e > 0 {
+        if e & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        e >>= 1;
+    }
+    result as u64
+}
+
+/// Looks up (or computes and caches) the parameters of `GF(modulus)`.
+pub fn field_params(modulus: u64) -> Result<Arc<FieldParams>, FieldError> {
+    if let Some(params) = cache().lock().unwrap().get(&modulus) {
+        return Ok(params.clone());
+    }
+    if !is_prime64(modulus) {
+        return Err(FieldError::NotPrime(modulus));
+    }
+
+    let group_order = modulus - 1;
+    let group_order_factors: Vec<(u64, usize)> = factorize64(group_order).into_iter().collect();
+    // g generates GF(p)^* iff g^((p-1)/q) != 1 for every prime q | p - 1.
+    let generator = (1..modulus)
+        .find(|&g| {
+            group_order_factors
+                .iter()
+                .all(|&(q, _)| pow_mod(g, group_order / q, modulus) != 1)
+        })
+        .expect("the multiplicative group of a prime field is cyclic");
+
+    let params = Arc::new(FieldParams {
+        modulus,
+        group_order_factors,
+        generator,
+        two_adicity: group_order.trailing_zeros(),
+    });
+    cache().lock().unwrap().insert(modulus, params.clone());
+    Ok(params)
+}
+
+/// A primitive `order`-th root of unity in `GF(modulus)`, as `g^((p-1)/order)`.
+pub fn root_of_unity(modulus: u64, order: u64) -> Result<u64, FieldError> {
+    let params = field_params(modulus)?;
+    let group_order = modulus - 1;
+    if order == 0 || !group_order.is_multiple_of(order) {
+        return Err(FieldError::NoRootOfUnity { order, group_order });
+    }
+    Ok(pow_mod(params.generator, group_order / order, modulus))
+}
+
+/// A primitive `2^log_n`-th root of unity, or `None` if `2^log_n` does not divide `p - 1`.
+pub fn two_adic_root_of_unity(modulus: u64, log_n: u32) -> Option<u64> {
+    if log_n >= 64 {
+        return None;
+    }
+    root_of_unity(modulus, 1 << log_n).ok()
+}
+
+impl<const MODULUS: u64> FieldElement<MODULUS> {
+    /// Whether `MODULUS` is prime, i.e. whether this type is actually a field.
+    pub fn is_prime_modulus() -> bool {
+        is_prime64(MODULUS)
+    }
+
+    /// The prime factorisation of `MODULUS - 1`, the order of the multiplicative group.
+    pub fn group_order_factors() -> Result<Vec<(u64, usize)>, FieldError> {
+        Ok(field_params(MODULUS)?.group_order_factors.clone())
+    }
+
+    /// The smallest generator of the multiplicative group.
+    pub fn generator() -> Result<Self, FieldError> {
+        Ok(FieldElement::new(field_params(MODULUS)?.generator))
+    }
+
+    /// A primitive `n`-th root of unity. Fails if `n` does not divide `MODULUS - 1`.
+    pub fn primitive_root_of_unity(n: u64) -> Result<Self, FieldError> {
+        root_of_unity(MODULUS, n).map(FieldElement::new)
+    }
+}
+
+#[cfg(test)]
+mod test_params {
+    use super::*;
+    use crate::fields::{BabyBear, Field, Goldilocks, KoalaBear, Mersenne31, MontFieldElement};
+
+    fn order_of<F: Field>(x: &F) -> u64 {
+        let mut acc = x.clone();
+        let mut order = 1;
+        while acc != F::one() {
+            acc *= x.clone();
+            order += 1;
+        }
+        order
+    }
+
+    #[test]
+    fn test_primality() {
+        assert!(FieldElement::<7>::is_prime_modulus());
+        assert!(FieldElement::<{ Goldilocks::MODULUS }>::is_prime_modulus());
+        assert!(!FieldElement::<15>::is_prime_modulus());
+        assert_eq!(FieldElement::<15>::generator(), Err(FieldError::NotPrime(15)));
+    }
+
+    #[test]
+    fn test_small_field_parameters() {
+        type F = FieldElement<17>;
+        assert_eq!(F::group_order_factors().unwrap(), vec![(2, 4)]);
+        assert_eq!(F::generator().unwrap(), F::new(3));
+        assert_eq!(order_of(&F::generator().unwrap()), 16);
+        assert_eq!(<F as Field>::two_adicity(), 4);
+
+        let omega = F::primitive_root_of_unity(8).unwrap();
+        assert_eq!(order_of(&omega), 8);
+        assert_eq!(
+            F::primitive_root_of_unity(3),
+            Err(FieldError::NoRootOfUnity { order: 3, group_order: 16 })
+        );
+        assert!(F::primitive_root_of_unity(0).is_err());
+    }
+
+    #[test]
+    fn test_known_generators() {
+        assert_eq!(FieldElement::<{ Goldilocks::MODULUS }>::generator().unwrap().value(), 7);
+        assert_eq!(FieldElement::<0x7800_0001>::generator().unwrap().value(), 31);
+        assert_eq!(FieldElement::<0x7f00_0001>::generator().unwrap().value(), 3);
+        assert_eq!(FieldElement::<0x7fff_ffff>::generator().unwrap().value(), 7);
+    }
+
+    #[test]
+    fn test_two_adicity_of_specialised_fields() {
+        assert_eq!(Goldilocks::two_adicity(), 32);
+        assert_eq!(BabyBear::two_adicity(), 27);
+        assert_eq!(KoalaBear::two_adicity(), 24);
+        assert_eq!(Mersenne31::two_adicity(), 1);
+        assert_eq!(MontFieldElement::<17>::two_adicity(), 4);
+    }
+
+    #[test]
+    fn test_two_adic_roots() {
+        let root = Goldilocks::two_adic_root_of_unity(32).unwrap();
+        assert_eq!(root.pow(1 << 32), Goldilocks::one());
+        assert_ne!(root.pow(1 << 31), Goldilocks::one());
+        assert_eq!(Goldilocks::two_adic_root_of_unity(33), None);
+
+        let root = BabyBear::two_adic_root_of_unity(4).unwrap();
+        assert_eq!(order_of(&root), 16);
+        assert_eq!(Mersenne31::two_adic_root_of_unity(1), Some(-Mersenne31::one()));
+        assert_eq!(Mersenne31::two_adic_root_of_unity(2), None);
+        assert_eq!(MontFieldElement::<17>::two_adic_root_of_unity(3).map(|r| order_of(&r)), Some(8));
+    }
+}
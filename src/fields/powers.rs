@@ -0,0 +1,152 @@
+//! Successive powers of a field element and fixed-base exponentiation.
+//!
+//! `powers_table(g, n)` costs `n - 1` multiplications, against `O(n log n)`
+//! for calling `g.pow(i)` for every `i`. `FixedBaseTable` trades memory for
+//! fewer multiplications when the same base is raised to many exponents.
+
+use crate::fields::Field;
+use rayon::prelude::*;
+
+/// Table length filled by one rayon task in [`powers_table_parallel`].
+pub const POWERS_CHUNK: usize = 1 << 12;
+
+/// Endless iterator over `start, start * base, start * base^2, ...`.
+#[derive(Clone, Debug)]
+pub struct Powers<F> {
+    base: F,
+    current: F,
+}
+
+impl<F: Field> Iterator for Powers<F> {
+    type Item = F;
+
+    fn next(&mut self) -> Option<F> {
+        let next = self.current.clone() * self.base.clone();
+        Some(std::mem::replace(&mut self.current, next))
+    }
+}
+
+/// `1, base, base^2, ...`
+pub fn powers<F: Field>(base: F) -> Powers<F> {
+    shifted_powers(F::one(), base)
+}
+
+/// `start, start * base, start * base^2, ...`, e.g. the points of a coset.
+pub fn shifted_powers<F: Field>(start: F, base: F) -> Powers<F> {
+    Powers { base, current: start }
+}
+
+/// `[1, base, ..., base^(n-1)]`.
+pub fn powers_table<F: Field>(base: &F, n: usize) -> Vec<F> {
+    powers(base.clone()).take(n).collect()
+}
+
+/// Same as [`powers_table`], filled in chunks of [`POWERS_CHUNK`] in parallel.
+/// Each chunk starts from its own `base^(k * POWERS_CHUNK)`, found by stepping
+/// with `base^POWERS_CHUNK`.
+pub fn powers_table_parallel<F: Field>(base: &F, n: usize) -> Vec<F> {
+    if n <= POWERS_CHUNK {
+        return powers_table(base, n);
+    }
+    let step = base.pow(POWERS_CHUNK as u64);
+    let starts: Vec<F> = powers(step).take(n.div_ceil(POWERS_CHUNK)).collect();
+
+    let mut table = vec![F::zero(); n];
+    table
+        .par_chunks_mut(POWERS_CHUNK)
+        .zip(starts)
+        .for_each(|(chunk, start)| {
+            for (slot, value) in chunk.iter_mut().zip(shifted_powers(start, base.clone())) {
+                *slot = value;
+            }
+        });
+    table
+}
+
+/// Precomputed `base^(d * 2^(w * i))` for every `w`-bit digit `d` and window `i`,
+/// so that `pow(e)` is at most one multiplication per non-zero digit of `e`
+/// (16 for the default 4-bit windows, instead of up to 128 for square-and-multiply).
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable<F> {
+    window_bits: u32,
+    /// `table[i][d] = base^(d << (window_bits * i))`.
+    table: Vec<Vec<F>>,
+}
+
+impl<F: Field> FixedBaseTable<F> {
+    pub const DEFAULT_WINDOW_BITS: u32 = 4;
+
+    pub fn new(base: &F) -> Self {
+        Self::with_window(base, Self::DEFAULT_WINDOW_BITS)
+    }
+
+    /// Builds the table with `window_bits`-bit digits: `ceil(64 / window_bits)`
+    /// rows of `2^window_bits` entries. Panics unless `1 <= window_bits <= 16`.
+    pub fn with_window(base: &F, window_bits: u32) -> Self {
+        assert!((1..=16).contains(&window_bits), "window must be 1 to 16 bits");
+        let windows = 64u32.div_ceil(window_bits);
+        let mut table = Vec::with_capacity(windows as usize);
+        let mut window_base = base.clone();
+        for _ in 0..windows {
+            let row = powers_table(&window_base, 1 << window_bits);
+            // Next row's base is this row's base to the 2^window_bits.
+            window_base = row[row.len() - 1].clone() * window_base;
+            table.push(row);
+        }
+        Self { window_bits, table }
+    }
+
+    /// `base^exp`.
+    pub fn pow(&self, exp: u64) -> F {
+        let mask = (1u64 << self.window_bits) - 1;
+        let mut result = F::one();
+        for (i, row) in self.table.iter().enumerate() {
+            let shift = self.window_bits as usize * i;
+            let digit = (exp >> shift) & mask;
+            if digit != 0 {
+                result *= row[digit as usize].clone();
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test_powers {
+    use super::*;
+    use crate::fields::{FieldElement, Goldilocks};
+
+    #[test]
+    fn test_powers_match_pow() {
+        let g = Goldilocks::new(7);
+        let table = powers_table(&g, 100);
+        assert_eq!(table.len(), 100);
+        for (i, value) in table.iter().enumerate() {
+            assert_eq!(*value, g.pow(i as u64));
+        }
+        assert!(powers_table(&g, 0).is_empty());
+
+        let coset: Vec<_> = shifted_powers(Goldilocks::new(3), g).take(5).collect();
+        assert_eq!(coset[4], Goldilocks::new(3) * g.pow(4));
+    }
+
+    #[test]
+    fn test_parallel_matches_serial() {
+        let g = Goldilocks::new(7);
+        for n in [1, POWERS_CHUNK, 2 * POWERS_CHUNK + 5] {
+            assert_eq!(powers_table_parallel(&g, n), powers_table(&g, n));
+        }
+    }
+
+    #[test]
+    fn test_fixed_base_table() {
+        let g = FieldElement::<{ Goldilocks::MODULUS }>::new(7);
+        let exps = [0, 1, 15, 16, 0xdead_beef, u64::MAX, Goldilocks::MODULUS - 1];
+        for window_bits in [1, 3, 4, 8] {
+            let table = FixedBaseTable::with_window(&g, window_bits);
+            for &e in &exps {
+                assert_eq!(table.pow(e), g.pow(e), "window {window_bits}, exp {e}");
+            }
+        }
+    }
+}
@@ -0,0 +1,512 @@
+//! The StarkNet / Cairo prime field, `p = 2^251 + 17 * 2^192 + 1`.
+//!
+//! Elements are four little-endian 64-bit limbs in Montgomery form with
+//! `R = 2^256`. Because `p = 1 (mod 2^64)`, the per-limb Montgomery factor
+//! `-p^{-1} mod 2^64` is simply `2^64 - 1`. `U256` is only used at the API
+//! boundary and as the reference in tests.
+
+use crate::fields::{Field, FieldError};
+use alloy::primitives::U256;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use rand_core::{OsRng, RngCore};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+use subtle::ConstantTimeEq;
+
+type Limbs = [u64; 4];
+
+/// `p`, least significant limb first.
+const P: Limbs = [1, 0, 0, 0x0800_0000_0000_0011];
+/// `-p^{-1} mod 2^64`.
+const NEG_INV: u64 = u64::MAX;
+/// `2^256 mod p`, the Montgomery form of one.
+const R: Limbs = [
+    0xffff_ffff_ffff_ffe1,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+    0x07ff_ffff_ffff_fdf0,
+];
+/// `2^512 mod p`, used to convert into Montgomery form.
+const R2: Limbs = [
+    0xffff_fd73_7e00_0401,
+    0x0000_0001_330f_ffff,
+    0xffff_ffff_ff6f_8000,
+    0x07ff_d4ab_5e00_8810,
+];
+
+/// `a + b * c + carry` as (low, high) words.
+#[inline(always)]
+fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + b as u128 * c as u128 + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+/// `a - b` and the final borrow.
+#[inline(always)]
+fn sub_limbs(a: &Limbs, b: &Limbs) -> (Limbs, bool) {
+    let mut out = [0u64; 4];
+    let mut borrow = false;
+    for i in 0..4 {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        out[i] = d;
+        borrow = b1 | b2;
+    }
+    (out, borrow)
+}
+
+/// `a + b`, assuming it fits in 256 bits (both operands are below `p < 2^252`).
+#[inline(always)]
+fn add_limbs(a: &Limbs, b: &Limbs) -> Limbs {
+    let mut out = [0u64; 4];
+    let mut carry = false;
+    for i in 0..4 {
+        let (s, c1) = a[i].overflowing_add(b[i]);
+        let (s, c2) = s.overflowing_add(carry as u64);
+        out[i] = s;
+        carry = c1 | c2;
+    }
+    out
+}
+
+/// Maps `x < 2p` to `x mod p`.
+#[inline(always)]
+fn reduce_once(x: Limbs) -> Limbs {
+    let (reduced, borrow) = sub_limbs(&x, &P);
+    if borrow {
+        x
+    } else {
+        reduced
+    }
+}
+
+/// `a * b * 2^-256 mod p` (CIOS Montgomery multiplication).
+fn mont_mul(a: &Limbs, b: &Limbs) -> Limbs {
+    let mut t = [0u64; 6];
+    for &bi in b.iter() {
+        // t += a * b_i
+        let mut carry = 0;
+        for j in 0..4 {
+            (t[j], carry) = mac(t[j], a[j], bi, carry);
+        }
+        let (s, c) = t[4].overflowing_add(carry);
+        t[4] = s;
+        t[5] = c as u64;
+
+        // t = (t + m * p) / 2^64, with m chosen so the low limb cancels.
+        let m = t[0].wrapping_mul(NEG_INV);
+        let (_, mut carry) = mac(t[0], m, P[0], 0);
+        for j in 1..4 {
+            (t[j - 1], carry) = mac(t[j], m, P[j], carry);
+        }
+        let (s, c) = t[4].overflowing_add(carry);
+        t[3] = s;
+        t[4] = t[5] + c as u64;
+    }
+    // t < 2p < 2^253, so t[4] is zero here.
+    reduce_once([t[0], t[1], t[2], t[3]])
+}
+
+/// An element of the StarkNet field, kept in Montgomery form.
+#[derive(Debug, Clone, Copy)]
+pub struct Stark252 {
+    mont: Limbs,
+}
+
+impl Stark252 {
+    /// `p = 2^251 + 17 * 2^192 + 1`.
+    pub const MODULUS: U256 = U256::from_limbs(P);
+
+    /// `p - 1 = 2^192 * (2^59 + 17)`.
+    pub const TWO_ADICITY: u32 = 192;
+
+    /// Reduces `value` modulo `p`.
+    pub fn new(value: U256) -> Self {
+        Self::from_canonical_limbs(value.reduce_mod(Self::MODULUS).into_limbs())
+    }
+
+    fn from_canonical_limbs(limbs: Limbs) -> Self {
+        Stark252 {
+            mont: mont_mul(&limbs, &R2),
+        }
+    }
+
+    pub fn from_u64(value: u64) -> Self {
+        Self::from_canonical_limbs([value, 0, 0, 0])
+    }
+
+    pub fn zero() -> Self {
+        Stark252 { mont: [0; 4] }
+    }
+
+    pub fn one() -> Self {
+        Stark252 { mont: R }
+    }
+
+    /// The canonical value in `[0, p)`.
+    pub fn value(&self) -> U256 {
+        U256::from_limbs(mont_mul(&self.mont, &[1, 0, 0, 0]))
+    }
+
+    pub fn random() -> Self {
+        Self::random_with(&mut OsRng)
+    }
+
+    /// Uniform sample: 252 random bits, retried until below `p` (about half the time).
+    pub fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        loop {
+            let mut limbs = [rng.next_u64(), rng.next_u64(), rng.next_u64(), rng.next_u64()];
+            limbs[3] &= (1 << 60) - 1;
+            if sub_limbs(&limbs, &P).1 {
+                return Self::from_canonical_limbs(limbs);
+            }
+        }
+    }
+
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    pub fn pow(&self, exp: u64) -> Self {
+        self.pow_u256(U256::from(exp))
+    }
+
+    /// Square-and-multiply over a 256-bit exponent, most significant bit first.
+    pub fn pow_u256(&self, exp: U256) -> Self {
+        let mut result = Self::one();
+        for i in (0..exp.bit_len()).rev() {
+            result = result.square();
+            if exp.bit(i) {
+                result *= *self;
+            }
+        }
+        result
+    }
+
+    /// `a^(p-2)`.
+    pub fn inverse(&self) -> Self {
+        self.pow_u256(Self::MODULUS - U256::from(2))
+    }
+
+    /// 32 big-endian bytes of the canonical value.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.value().to_be_bytes::<32>()
+    }
+}
+
+impl PartialEq for Stark252 {
+    fn eq(&self, other: &Self) -> bool {
+        let mut eq = self.mont[0].ct_eq(&other.mont[0]);
+        for i in 1..4 {
+            eq &= self.mont[i].ct_eq(&other.mont[i]);
+        }
+        eq.unwrap_u8() == 1
+    }
+}
+
+impl Eq for Stark252 {}
+
+impl Hash for Stark252 {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.mont.hash(state);
+    }
+}
+
+impl Add for Stark252 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Stark252 {
+            mont: reduce_once(add_limbs(&self.mont, &rhs.mont)),
+        }
+    }
+}
+
+impl AddAssign for Stark252 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Stark252 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (diff, borrow) = sub_limbs(&self.mont, &rhs.mont);
+        let mont = if borrow { add_limbs(&diff, &P) } else { diff };
+        Stark252 { mont }
+    }
+}
+
+impl SubAssign for Stark252 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for Stark252 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Stark252 {
+            mont: mont_mul(&self.mont, &rhs.mont),
+        }
+    }
+}
+
+impl MulAssign for Stark252 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for Stark252 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.inverse()
+    }
+}
+
+impl DivAssign for Stark252 {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self *= rhs.inverse();
+    }
+}
+
+impl Neg for Stark252 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Stark252::zero() - self
+    }
+}
+
+impl From<i128> for Stark252 {
+    fn from(value: i128) -> Self {
+        let magnitude = Stark252::new(U256::from(value.unsigned_abs()));
+        if value < 0 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+impl fmt::Display for Stark252 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value(), f)
+    }
+}
+
+impl fmt::LowerHex for Stark252 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.value(), f)
+    }
+}
+
+/// Parses a canonical decimal value, or hex with a `0x` prefix.
+impl FromStr for Stark252 {
+    type Err = FieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let value = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) => U256::from_str_radix(hex, 16),
+            None => U256::from_str_radix(s, 10),
+        }
+        .map_err(|_| FieldError::InvalidLiteral(s.to_string()))?;
+        if value >= Self::MODULUS {
+            return Err(FieldError::NonCanonicalU256 {
+                value,
+                modulus: Self::MODULUS,
+            });
+        }
+        Ok(Self::from_canonical_limbs(value.into_limbs()))
+    }
+}
+
+impl Distribution<Stark252> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Stark252 {
+        Stark252::random_with(rng)
+    }
+}
+
+impl Field for Stark252 {
+    fn zero() -> Self {
+        Stark252::zero()
+    }
+
+    fn one() -> Self {
+        Stark252::one()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        Stark252::from_u64(value)
+    }
+
+    fn inverse(&self) -> Self {
+        Stark252::inverse(self)
+    }
+
+    fn pow(&self, exp: u64) -> Self {
+        Stark252::pow(self, exp)
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        Stark252::to_bytes(self).to_vec()
+    }
+
+    /// Expects exactly 32 big-endian bytes holding a value below `p`.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, FieldError> {
+        if bytes.len() != 32 {
+            return Err(FieldError::InvalidLength {
+                expected: 32,
+                actual: bytes.len(),
+            });
+        }
+        let value = U256::from_be_slice(bytes);
+        if value >= Self::MODULUS {
+            return Err(FieldError::NonCanonicalU256 {
+                value,
+                modulus: Self::MODULUS,
+            });
+        }
+        Ok(Self::from_canonical_limbs(value.into_limbs()))
+    }
+
+    /// One `U256` reduction instead of the generic byte-by-byte Horner loop.
+    fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
+        bytes.chunks(32).fold(Stark252::zero(), |acc, chunk| {
+            let shift = Stark252::from_u64(256).pow(chunk.len() as u64);
+            acc * shift + Stark252::new(U256::from_be_slice(chunk))
+        })
+    }
+
+    fn random_with<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Stark252::random_with(rng)
+    }
+
+    fn characteristic() -> U256 {
+        Self::MODULUS
+    }
+
+    fn square(&self) -> Self {
+        Stark252::square(self)
+    }
+
+    fn two_adicity() -> u32 {
+        Self::TWO_ADICITY
+    }
+
+    fn two_adic_root_of_unity(log_n: u32) -> Option<Self> {
+        if log_n > Self::TWO_ADICITY {
+            return None;
+        }
+        // 3 generates the multiplicative group.
+        let exp = (Self::MODULUS - U256::from(1)) >> log_n as usize;
+        Some(Stark252::from_u64(3).pow_u256(exp))
+    }
+
+    fn multiplicative_generator() -> Option<Self> {
+        Some(Stark252::from_u64(3))
+    }
+}
+
+#[cfg(test)]
+mod test_stark252 {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    const P_U256: U256 = Stark252::MODULUS;
+
+    #[test]
+    fn test_constants() {
+        let p = (U256::from(1) << 251) + (U256::from(17) << 192) + U256::from(1);
+        assert_eq!(P_U256, p);
+        assert_eq!(U256::from_limbs(R), (U256::MAX % p) + U256::from(1));
+        assert_eq!(
+            U256::from_limbs(R2),
+            U256::from_limbs(R).mul_mod(U256::from_limbs(R), p)
+        );
+        assert_eq!(P[0].wrapping_mul(NEG_INV), u64::MAX);
+        assert_eq!(Stark252::one().value(), U256::from(1));
+    }
+
+    #[test]
+    fn test_matches_u256_reference() {
+        let mut rng = ChaCha20Rng::seed_from_u64(252);
+        for _ in 0..200 {
+            let (a, b) = (Stark252::random_with(&mut rng), Stark252::random_with(&mut rng));
+            let (x, y) = (a.value(), b.value());
+            assert!(x < P_U256);
+            assert_eq!((a + b).value(), x.add_mod(y, P_U256));
+            assert_eq!((a * b).value(), x.mul_mod(y, P_U256));
+            assert_eq!((a - b).value(), x.add_mod(P_U256 - y, P_U256));
+            assert_eq!((-a).value(), (P_U256 - x).reduce_mod(P_U256));
+            assert_eq!(Stark252::new(x), a);
+        }
+    }
+
+    #[test]
+    fn test_edges_and_inverse() {
+        let minus_one = Stark252::new(P_U256 - U256::from(1));
+        assert_eq!(minus_one * minus_one, Stark252::one());
+        assert_eq!(minus_one + Stark252::one(), Stark252::zero());
+        assert_eq!(Stark252::new(P_U256), Stark252::zero());
+        assert_eq!(Stark252::from(-1i128), minus_one);
+
+        // 1/2 = (p + 1) / 2
+        let half: Stark252 = "0x400000000000008800000000000000000000000000000000000000000000001"
+            .parse()
+            .unwrap();
+        assert_eq!(Stark252::from_u64(2).inverse(), half);
+        let a = Stark252::from_u64(0xdead_beef);
+        assert_eq!(a * a.inverse(), Stark252::one());
+    }
+
+    #[test]
+    fn test_two_adic_root() {
+        // The value used by Cairo / lambdaworks for the 2^192-th root of unity.
+        let expected: Stark252 = "0x5282db87529cfa3f0464519c8b0fa5ad187148e11a61616070024f42f8ef94"
+            .parse()
+            .unwrap();
+        let root = Stark252::two_adic_root_of_unity(192).unwrap();
+        assert_eq!(root, expected);
+        assert_eq!(root.pow_u256(U256::from(1) << 192), Stark252::one());
+        assert_ne!(root.pow_u256(U256::from(1) << 191), Stark252::one());
+        assert_eq!(Stark252::two_adic_root_of_unity(193), None);
+    }
+
+    #[test]
+    fn test_encoding() {
+        let a = Stark252::from_u64(12345);
+        assert_eq!(a.to_string(), "12345");
+        assert_eq!("12345".parse::<Stark252>(), Ok(a));
+        let bytes = Field::to_bytes(&a);
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(<Stark252 as Field>::from_bytes(&bytes), Ok(a));
+        assert!(<Stark252 as Field>::from_bytes(&P_U256.to_be_bytes::<32>()).is_err());
+        assert!(matches!("0xzz".parse::<Stark252>(), Err(FieldError::InvalidLiteral(_))));
+
+        let digest = [0xffu8; 32];
+        assert_eq!(
+            <Stark252 as Field>::from_be_bytes_mod_order(&digest).value(),
+            U256::MAX.reduce_mod(P_U256)
+        );
+    }
+
+    #[test]
+    fn test_coset_fri_over_stark252() {
+        let fri = crate::fri::CosetFri::<Stark252>::with_domain_size(16).unwrap();
+        let domain = fri.generate_coset_domain();
+        assert_eq!(domain.len(), 16);
+        assert_eq!(fri.omega.pow(16), Stark252::one());
+    }
+}
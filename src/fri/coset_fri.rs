@@ -1,46 +1,89 @@
 //! in the FRI protocol, shifting a subgroup by an offset `g`.
 
-use crate::fields::element::FieldElement;
+use crate::fields::{powers_table_parallel, Field};
+use thiserror::Error;
+
+/// Reasons a coset domain description is rejected.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CosetFriError {
+    #[error("domain size {0} is not a power of two")]
+    DomainSizeNotPowerOfTwo(usize),
+
+    #[error("omega is not a primitive root of unity of order {0}")]
+    WrongOmegaOrder(usize),
+
+    #[error("offset is zero or lies in the subgroup generated by omega")]
+    OffsetInSubgroup,
+
+    #[error("the field has no multiplicative subgroup of order {0}")]
+    UnsupportedDomainSize(usize),
+}
 
 /// Defines a Coset-FRI configuration for domain generation:
 ///    D = { offset * omega^i | i = 0..domain_size-1 }
 /// where omega has order = domain_size (typically 2^k).
 #[derive(Clone, Debug)]
-pub struct CosetFri<const M: u64> {
+pub struct CosetFri<F> {
     /// The coset offset `g`, not in <omega>.
-    pub offset: FieldElement<M>,
+    pub offset: F,
 
     /// The subgroup generator of order `domain_size`.
-    pub omega: FieldElement<M>,
+    pub omega: F,
 
     /// The size of the initial domain, e.g. 2^k.
     pub domain_size: usize,
 }
 
-impl<const M: u64> CosetFri<M> {
-    /// Creates a new CosetFri instance.
-    pub fn new(offset: FieldElement<M>, omega: FieldElement<M>, domain_size: usize) -> Self {
-        Self {
+impl<F: Field> CosetFri<F> {
+    /// Creates a new CosetFri instance, checking that `omega` has order exactly
+    /// `domain_size` and that the coset `offset * <omega>` is disjoint from `<omega>`.
+    pub fn new(offset: F, omega: F, domain_size: usize) -> Result<Self, CosetFriError> {
+        if !domain_size.is_power_of_two() {
+            return Err(CosetFriError::DomainSizeNotPowerOfTwo(domain_size));
+        }
+        let n = domain_size as u64;
+        // For a power of two n, omega has order n iff omega^n = 1 and omega^(n/2) != 1.
+        if omega.pow(n) != F::one() || (n > 1 && omega.pow(n / 2) == F::one()) {
+            return Err(CosetFriError::WrongOmegaOrder(domain_size));
+        }
+        // <omega> is the unique subgroup of order n, so offset is in it iff offset^n = 1.
+        if offset.is_zero() || offset.pow(n) == F::one() {
+            return Err(CosetFriError::OffsetInSubgroup);
+        }
+        Ok(Self {
             offset,
             omega,
             domain_size,
+        })
+    }
+
+    /// Picks `omega` and `offset` from the field itself: the canonical
+    /// `domain_size`-th root of unity and the multiplicative generator.
+    pub fn with_domain_size(domain_size: usize) -> Result<Self, CosetFriError> {
+        if !domain_size.is_power_of_two() {
+            return Err(CosetFriError::DomainSizeNotPowerOfTwo(domain_size));
         }
+        let omega = F::two_adic_root_of_unity(domain_size.trailing_zeros())
+            .ok_or(CosetFriError::UnsupportedDomainSize(domain_size))?;
+        let offset = F::multiplicative_generator()
+            .ok_or(CosetFriError::UnsupportedDomainSize(domain_size))?;
+        Self::new(offset, omega, domain_size)
     }
 
     /// Generates the initial coset domain:
     ///      D = { offset * (omega^i) : i in [0..domain_size) }
-    pub fn generate_coset_domain(&self) -> Vec<FieldElement<M>> {
-        (0..self.domain_size)
-            .map(|i| self.offset * self.omega.pow(i as u64))
-            .collect()
+    pub fn generate_coset_domain(&self) -> Vec<F> {
+        let mut domain = powers_table_parallel(&self.omega, self.domain_size);
+        F::scale_slice(&mut domain, &self.offset);
+        domain
     }
 
     /// Squares each element of the current domain to build the next domain
     /// of half the length. The typical "FRI folding" step will use half of the domain.
     pub fn next_coset_domain(
         &self,
-        current_domain: &[FieldElement<M>]
-    ) -> Vec<FieldElement<M>> {
+        current_domain: &[F]
+    ) -> Vec<F> {
         // Note: some FRI implementations only take the first half of current_domain
         // for the next round. This is a design choice. Here we show the "square all" step.
         current_domain
@@ -50,3 +93,61 @@ impl<const M: u64> CosetFri<M> {
     }
 }
 
+#[cfg(test)]
+mod test_coset_fri {
+    use super::*;
+    use crate::fields::{FieldElement, Goldilocks};
+
+    type F = FieldElement<17>;
+
+    #[test]
+    fn test_valid_parameters() {
+        // 3 generates GF(17)^*, 3^2 = 9 has order 8.
+        let fri = CosetFri::new(F::new(3), F::new(9), 8).unwrap();
+        let domain = fri.generate_coset_domain();
+        assert_eq!(domain.len(), 8);
+        assert!(domain.iter().all(|d| d.pow(8) != F::one()));
+    }
+
+    #[test]
+    fn test_rejects_bad_parameters() {
+        assert_eq!(
+            CosetFri::new(F::new(3), F::new(9), 6).unwrap_err(),
+            CosetFriError::DomainSizeNotPowerOfTwo(6)
+        );
+        // 16 = -1 has order 2, not 8.
+        assert_eq!(
+            CosetFri::new(F::new(3), F::new(16), 8).unwrap_err(),
+            CosetFriError::WrongOmegaOrder(8)
+        );
+        // 2 = 3^14 lies in <9>.
+        assert_eq!(
+            CosetFri::new(F::new(2), F::new(9), 8).unwrap_err(),
+            CosetFriError::OffsetInSubgroup
+        );
+        assert_eq!(
+            CosetFri::new(F::zero(), F::new(9), 8).unwrap_err(),
+            CosetFriError::OffsetInSubgroup
+        );
+    }
+
+    #[test]
+    fn test_with_domain_size() {
+        let fri = CosetFri::<F>::with_domain_size(8).unwrap();
+        assert_eq!(fri.omega.pow(8), F::one());
+        assert_eq!(fri.offset, F::new(3));
+        assert_eq!(
+            CosetFri::<F>::with_domain_size(32).unwrap_err(),
+            CosetFriError::UnsupportedDomainSize(32)
+        );
+        // The full 2-power subgroup contains every element with a 2-power order,
+        // including the generator of a field with p - 1 a power of two.
+        assert_eq!(
+            CosetFri::<F>::with_domain_size(16).unwrap_err(),
+            CosetFriError::OffsetInSubgroup
+        );
+
+        let fri = CosetFri::<Goldilocks>::with_domain_size(1 << 10).unwrap();
+        assert_eq!(fri.generate_coset_domain().len(), 1 << 10);
+    }
+}
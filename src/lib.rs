@@ -10,4 +10,13 @@ pub mod polynomial;
 pub mod utils;
 pub mod channel;
 
-// pub mod fri;
\ No newline at end of file
+pub mod fri;
+
+/// Dependencies named by the expansion of [`define_field!`], so that calling
+/// crates do not need them in their own manifest.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloy;
+    pub use rand;
+    pub use rand_core;
+}
\ No newline at end of file
@@ -1,24 +1,26 @@
-use crate::fields::FieldElement;
+use crate::fields::Field;
+use std::marker::PhantomData;
 use rs_merkle::algorithms::Sha256;
 use rs_merkle::{self, Hasher};
 
-pub struct MerkleTree<const MODULUS: u64> {
+pub struct MerkleTree<F> {
     inner: rs_merkle::MerkleTree<rs_merkle::algorithms::Sha256>,
+    _field: PhantomData<F>,
 }
 
-impl <const MODULUS: u64> MerkleTree<MODULUS> {
-    pub fn new(data: Vec<FieldElement<MODULUS>>) -> Self {
+impl <F: Field> MerkleTree<F> {
+    pub fn new(data: Vec<F>) -> Self {
         let hashed_data: Vec<[u8; 32]> = data
         .into_iter()
         .map(|d| {
-            let bytes = d.value().to_be_bytes(); // big-endian
+            let bytes = d.to_bytes(); // big-endian
             Sha256::hash(&bytes)
         })
         .collect();
         let inner =
             rs_merkle::MerkleTree::<rs_merkle::algorithms::Sha256>::from_leaves(&hashed_data);
 
-        MerkleTree { inner }
+        MerkleTree { inner, _field: PhantomData }
     }
 
     pub fn root(&self) -> String {
@@ -0,0 +1,197 @@
+//! Barycentric evaluation of a polynomial known only by its values on a domain.
+//!
+//! With `Z(x) = ∏ (x - x_i)` and weights `w_i = 1 / Z'(x_i)`, the polynomial of
+//! degree below `n` taking `y_i` at `x_i` satisfies
+//!
+//! ```text
+//! f(z) = Z(z) * sum_i w_i y_i / (z - x_i)
+//! ```
+//!
+//! so after precomputing the weights each `f(z)` costs one batched inversion and
+//! `O(n)` multiplications. On a coset `g<omega>` of size `n`, `Z(z) = z^n - g^n`
+//! and `w_i = x_i / (n g^n)`, so the weights take `O(n)`; for arbitrary points
+//! they come from the subproduct tree.
+
+use crate::fields::{ExtensionOf, Field};
+use crate::fri::CosetFri;
+use crate::polynomial::{CosetDomain, Polynomial, PolynomialError, SubproductTree};
+use rayon::prelude::*;
+
+/// Precomputed barycentric weights for one point set, reusable for every
+/// vector of values on it.
+#[derive(Clone, Debug)]
+pub struct BarycentricEvaluator<F: Field> {
+    points: Vec<F>,
+    /// `(w_i = 1 / Z'(x_i))`, in the order of `points`.
+    weights: Vec<F>,
+    vanishing: Vanishing<F>,
+}
+
+#[derive(Clone, Debug)]
+enum Vanishing<F> {
+    /// `x^size - constant`.
+    Coset { size: usize, constant: F },
+    Points(Polynomial<F>),
+}
+
+impl<F: Field> BarycentricEvaluator<F> {
+    /// Weights for a subgroup or coset domain, in `O(n)`.
+    pub fn for_domain(domain: &CosetDomain<F>) -> Self {
+        let n = domain.size();
+        let constant = domain.vanishing_constant();
+        let points = domain.elements();
+        // Z'(x_i) = n x_i^(n-1) = n g^n / x_i.
+        let mut weights = points.clone();
+        F::scale_slice(&mut weights, &(F::from_u64(n as u64) * constant.clone()).inverse());
+        BarycentricEvaluator { points, weights, vanishing: Vanishing::Coset { size: n, constant } }
+    }
+
+    /// Weights for arbitrary points, in `O(M(n) log n)`. Fails with
+    /// `DuplicatePoint` if the points are not distinct.
+    pub fn for_points(points: &[F]) -> Result<Self, PolynomialError> {
+        let tree = SubproductTree::new(points);
+        let weights = tree.barycentric_weights()?;
+        let z = tree.vanishing_polynomial().clone();
+        Ok(BarycentricEvaluator { points: points.to_vec(), weights, vanishing: Vanishing::Points(z) })
+    }
+
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    pub fn weights(&self) -> &[F] {
+        &self.weights
+    }
+
+    /// `f(z)` for the polynomial of degree below `n` with `f(x_i) = values[i]`.
+    /// If `z` is one of the points, this is the matching value.
+    pub fn evaluate_at(&self, values: &[F], z: &F) -> Result<F, PolynomialError> {
+        self.check_len(values)?;
+        Ok(self.evaluate_lifted(values, z, |x| x))
+    }
+
+    /// [`Self::evaluate_at`] at each of `zs`, in parallel.
+    pub fn evaluate_batch(&self, values: &[F], zs: &[F]) -> Result<Vec<F>, PolynomialError> {
+        self.check_len(values)?;
+        Ok(zs.par_iter().map(|z| self.evaluate_lifted(values, z, |x| x)).collect())
+    }
+
+    /// [`Self::evaluate_at`] at a point of an extension field, e.g. a DEEP
+    /// out-of-domain sample.
+    pub fn evaluate_at_extension<E: ExtensionOf<F>>(&self, values: &[F], z: &E) -> Result<E, PolynomialError> {
+        self.check_len(values)?;
+        Ok(self.evaluate_lifted(values, z, E::from))
+    }
+
+    fn check_len(&self, values: &[F]) -> Result<(), PolynomialError> {
+        if values.len() != self.points.len() {
+            return Err(PolynomialError::LengthMismatch { xs: self.points.len(), ys: values.len() });
+        }
+        Ok(())
+    }
+
+    /// The barycentric formula with the points and values embedded by `lift`.
+    fn evaluate_lifted<E: Field>(&self, values: &[F], z: &E, lift: impl Fn(F) -> E) -> E {
+        let mut diffs: Vec<E> = Vec::with_capacity(self.points.len());
+        for (x, y) in self.points.iter().zip(values) {
+            let diff = z.clone() - lift(x.clone());
+            if diff.is_zero() {
+                return lift(y.clone());
+            }
+            diffs.push(diff);
+        }
+        E::batch_inverse(&mut diffs);
+
+        let mut sum = E::zero();
+        for ((inv, w), y) in diffs.into_iter().zip(&self.weights).zip(values) {
+            sum += inv * lift(w.clone() * y.clone());
+        }
+        let z_at = match &self.vanishing {
+            Vanishing::Coset { size, constant } => z.pow(*size as u64) - lift(constant.clone()),
+            Vanishing::Points(poly) => {
+                poly.coefficients.iter().rev().fold(E::zero(), |acc, c| acc * z.clone() + lift(c.clone()))
+            }
+        };
+        z_at * sum
+    }
+}
+
+impl<F: Field> From<&CosetFri<F>> for BarycentricEvaluator<F> {
+    fn from(fri: &CosetFri<F>) -> Self {
+        Self::for_domain(&CosetDomain::from(fri))
+    }
+}
+
+#[cfg(test)]
+mod test_barycentric {
+    use super::*;
+    use crate::fields::{Ext2, FieldElement, Goldilocks};
+    use crate::polynomial::ops::generate_random_polynomial;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    #[test]
+    fn test_domain_matches_horner() {
+        let mut rng = ChaCha20Rng::seed_from_u64(24);
+        let coset = CosetDomain::coset(Goldilocks::multiplicative_generator().unwrap(), 64).unwrap();
+        for domain in [CosetDomain::subgroup(64).unwrap(), coset] {
+            let p: Polynomial<Goldilocks> = generate_random_polynomial(63, &mut rng);
+            let values = domain.evaluate(&p);
+            let evaluator = BarycentricEvaluator::for_domain(&domain);
+            let zs: Vec<Goldilocks> = (0..10).map(|_| Goldilocks::random_with(&mut rng)).collect();
+            let expected: Vec<Goldilocks> = zs.iter().map(|z| p.evaluate(*z)).collect();
+            assert_eq!(evaluator.evaluate_batch(&values, &zs).unwrap(), expected);
+            assert_eq!(evaluator.evaluate_at(&values, &zs[0]).unwrap(), expected[0]);
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_points_match_horner() {
+        let mut rng = ChaCha20Rng::seed_from_u64(124);
+        let points: Vec<Goldilocks> = (0..100).map(|_| Goldilocks::random_with(&mut rng)).collect();
+        let p: Polynomial<Goldilocks> = generate_random_polynomial(99, &mut rng);
+        let values: Vec<Goldilocks> = points.iter().map(|x| p.evaluate(*x)).collect();
+        let evaluator = BarycentricEvaluator::for_points(&points).unwrap();
+        let z = Goldilocks::random_with(&mut rng);
+        assert_eq!(evaluator.evaluate_at(&values, &z).unwrap(), p.evaluate(z));
+    }
+
+    #[test]
+    fn test_z_in_domain() {
+        type F = FieldElement<17>;
+        let domain = CosetDomain::<F>::coset(F::new(3), 8).unwrap();
+        let values: Vec<F> = (0..8).map(F::new).collect();
+        let evaluator = BarycentricEvaluator::for_domain(&domain);
+        let zs = domain.elements();
+        assert_eq!(evaluator.evaluate_batch(&values, &zs).unwrap(), values);
+
+        let fri = CosetFri::<F>::with_domain_size(8).unwrap();
+        let evaluator = BarycentricEvaluator::from(&fri);
+        assert_eq!(evaluator.points(), fri.generate_coset_domain());
+    }
+
+    #[test]
+    fn test_extension_point() {
+        type F = FieldElement<2013265921>;
+        type E = Ext2<2013265921, 31>;
+        let mut rng = ChaCha20Rng::seed_from_u64(224);
+        let domain = CosetDomain::<F>::coset(F::new(31), 32).unwrap();
+        let p: Polynomial<F> = generate_random_polynomial(31, &mut rng);
+        let values = domain.evaluate(&p);
+        let z = E::new([F::new(5), F::new(9)]);
+        let evaluator = BarycentricEvaluator::for_domain(&domain);
+        assert_eq!(evaluator.evaluate_at_extension(&values, &z).unwrap(), p.evaluate_extension(&z));
+    }
+
+    #[test]
+    fn test_errors() {
+        type F = FieldElement<17>;
+        let points = [F::new(1), F::new(5), F::new(1)];
+        assert_eq!(BarycentricEvaluator::for_points(&points).unwrap_err(), PolynomialError::DuplicatePoint);
+        let evaluator = BarycentricEvaluator::for_points(&points[..2]).unwrap();
+        assert_eq!(
+            evaluator.evaluate_at(&[F::one()], &F::new(2)),
+            Err(PolynomialError::LengthMismatch { xs: 2, ys: 1 })
+        );
+    }
+}
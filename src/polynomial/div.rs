@@ -0,0 +1,236 @@
+//! Power-series inversion and fast division with remainder.
+//!
+//! With `rev_k(p) = x^k p(1/x)`, the quotient of `a` (degree `n`) by `b`
+//! (degree `m`) satisfies `rev(q) = rev(a) * rev(b)^{-1} mod x^(n-m+1)`, so
+//! division costs one power-series inverse and two products: `O(M(n))` where
+//! `M` is the cost of [`mul::multiply`].
+//!
+//! Division by `x - a`, by `x^n - c` and by a few linear factors has dedicated
+//! linear-time routines that also report whether the division was exact.
+
+use crate::fields::Field;
+use crate::polynomial::{mul, PolynomialError};
+
+/// Quotient length and divisor length from which `Polynomial::div_rem` switches
+/// to [`div_rem_fast`]. Both must reach it: long division costs
+/// `O(quotient_len * divisor_len)`, which is already small when either is short.
+/// In `benches/poly_ops.rs` (`DivCrossover_*`) the two break even at about 512
+/// for the 31-bit fields.
+pub const FAST_DIV_THRESHOLD: usize = 512;
+
+/// The first `n` coefficients of `1 / f` as a power series, or `None` if
+/// `f(0) = 0` (then `f` has no inverse modulo `x^n`).
+///
+/// Newton iteration `g <- g * (2 - f * g)` doubles the number of correct
+/// coefficients on each step, starting from `g = 1 / f(0)`.
+pub fn inverse_mod_xn<F: Field>(f: &[F], n: usize) -> Option<Vec<F>> {
+    let f0 = f.first()?;
+    if f0.is_zero() {
+        return None;
+    }
+    if n == 0 {
+        return Some(Vec::new());
+    }
+    let mut g = vec![f0.inverse()];
+    let mut precision = 1;
+    while precision < n {
+        precision = (2 * precision).min(n);
+        // e = f * g - 1 mod x^precision; its low `g.len()` coefficients are zero.
+        let mut e = mul::multiply(&f[..f.len().min(precision)], &g);
+        e.truncate(precision);
+        e[0] -= F::one();
+        // g <- g - g * e mod x^precision
+        let mut correction = mul::multiply(&g, &e);
+        correction.truncate(precision);
+        g.resize(precision, F::zero());
+        F::sub_slices(&mut g[..correction.len()], &correction);
+    }
+    Some(g)
+}
+
+/// Quotient and remainder of `a / b` via [`inverse_mod_xn`]; both are returned
+/// untrimmed (`quotient.len() = a.len() - b.len() + 1`, `remainder.len() = b.len() - 1`).
+/// Panics if `b` is empty or has a zero leading coefficient, or if `a` is
+/// shorter than `b`.
+pub fn div_rem_fast<F: Field>(a: &[F], b: &[F]) -> (Vec<F>, Vec<F>) {
+    let lead = b.last().expect("Division by zero polynomial");
+    assert!(!lead.is_zero(), "divisor has a zero leading coefficient");
+    assert!(a.len() >= b.len(), "dividend is shorter than the divisor");
+
+    let q_len = a.len() - b.len() + 1;
+    let rev_a: Vec<F> = a.iter().rev().take(q_len).cloned().collect();
+    let rev_b: Vec<F> = b.iter().rev().take(q_len).cloned().collect();
+    let rev_b_inv = inverse_mod_xn(&rev_b, q_len).expect("leading coefficient is non-zero");
+
+    let mut quotient = mul::multiply(&rev_a, &rev_b_inv);
+    quotient.truncate(q_len);
+    quotient.reverse();
+
+    // r = a - b * q, which vanishes above degree deg(b) - 1.
+    let r_len = b.len() - 1;
+    let mut remainder = a[..r_len].to_vec();
+    let bq = mul::multiply(b, &quotient);
+    F::sub_slices(&mut remainder, &bq[..r_len]);
+    (quotient, remainder)
+}
+
+/// Synthetic division by `x - a` in one pass: returns the quotient (one
+/// coefficient shorter than `p`) and the remainder `p(a)`.
+pub fn synthetic_division<F: Field>(p: &[F], a: &F) -> (Vec<F>, F) {
+    let Some((lead, rest)) = p.split_last() else {
+        return (Vec::new(), F::zero());
+    };
+    let mut quotient = vec![F::zero(); rest.len()];
+    let mut carry = lead.clone();
+    for (i, coeff) in rest.iter().enumerate().rev() {
+        quotient[i] = carry.clone();
+        carry = coeff.clone() + carry * a.clone();
+    }
+    (quotient, carry)
+}
+
+/// Exact division by `x^n - c` (a coset vanishing polynomial when `c` is
+/// `offset^n`) in `O(p.len())`. Fails with `NotDivisible` if there is a remainder.
+/// Panics if `n` is zero.
+pub fn divide_by_x_n_minus_c<F: Field>(p: &[F], n: usize, c: &F) -> Result<Vec<F>, PolynomialError> {
+    assert!(n > 0, "x^0 - c is a constant");
+    if p.len() <= n {
+        return if p.iter().all(F::is_zero) {
+            Ok(Vec::new())
+        } else {
+            Err(PolynomialError::NotDivisible)
+        };
+    }
+    // Working down from the top, p_i = q_{i-n} - c * q_i.
+    let mut quotient = p[n..].to_vec();
+    for i in (0..quotient.len().saturating_sub(n)).rev() {
+        let carry = quotient[i + n].clone() * c.clone();
+        quotient[i] += carry;
+    }
+    // The remainder is p_i + c * q_i for i < n.
+    let exact = (0..n).all(|i| {
+        let q_i = quotient.get(i).cloned().unwrap_or_else(F::zero);
+        (p[i].clone() + c.clone() * q_i).is_zero()
+    });
+    if exact {
+        Ok(quotient)
+    } else {
+        Err(PolynomialError::NotDivisible)
+    }
+}
+
+/// Exact division by `(x - roots[0]) * (x - roots[1]) * ...` with one synthetic
+/// division per root, `O(p.len() * roots.len())`. Meant for a handful of roots;
+/// fails with `NotDivisible` as soon as one of them is not a root.
+pub fn divide_by_linear_factors<F: Field>(p: &[F], roots: &[F]) -> Result<Vec<F>, PolynomialError> {
+    let mut quotient = p.to_vec();
+    for root in roots {
+        let (q, remainder) = synthetic_division(&quotient, root);
+        if !remainder.is_zero() {
+            return Err(PolynomialError::NotDivisible);
+        }
+        quotient = q;
+    }
+    Ok(quotient)
+}
+
+#[cfg(test)]
+mod test_div {
+    use super::*;
+    use crate::fields::{FieldElement, Goldilocks, Mersenne31};
+    use crate::polynomial::ops::generate_random_polynomial;
+    use crate::polynomial::Polynomial;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+
+    #[test]
+    fn test_inverse_mod_xn() {
+        let mut rng = ChaCha20Rng::seed_from_u64(20);
+        for n in [1, 2, 7, 64, 300] {
+            let f: Polynomial<Goldilocks> = generate_random_polynomial(n + 5, &mut rng);
+            let Some(g) = inverse_mod_xn(&f.coefficients, n) else {
+                continue; // f(0) = 0
+            };
+            assert_eq!(g.len(), n);
+            let mut product = mul::multiply(&f.coefficients, &g);
+            product.truncate(n);
+            let mut one = vec![Goldilocks::zero(); n];
+            one[0] = Goldilocks::one();
+            assert_eq!(product, one);
+        }
+
+        // 1 / (1 - x) = 1 + x + x^2 + ...
+        type F = FieldElement<7>;
+        let g = inverse_mod_xn(&[F::one(), -F::one()], 5).unwrap();
+        assert_eq!(g, vec![F::one(); 5]);
+        assert!(inverse_mod_xn(&[F::zero(), F::one()], 3).is_none());
+        assert!(inverse_mod_xn::<F>(&[], 3).is_none());
+    }
+
+    #[test]
+    fn test_fast_matches_naive() {
+        let mut rng = ChaCha20Rng::seed_from_u64(2020);
+        for _ in 0..10 {
+            let m = rng.gen_range(0..200);
+            let n = m + rng.gen_range(0..300);
+            let a: Polynomial<Mersenne31> = generate_random_polynomial(n, &mut rng);
+            let b: Polynomial<Mersenne31> = generate_random_polynomial(m, &mut rng);
+            if b.is_zero() || a.degree < b.degree {
+                continue;
+            }
+            let (q, r) = div_rem_fast(&a.coefficients, &b.coefficients);
+            let (q_naive, r_naive) = a.div_rem_naive(&b);
+            assert_eq!(Polynomial::new(q), q_naive, "n={n} m={m}");
+            assert_eq!(Polynomial::new(r), r_naive, "n={n} m={m}");
+        }
+    }
+
+    #[test]
+    fn test_synthetic_division() {
+        type F = FieldElement<17>;
+        // 3x^3 + 2x + 5 = (x - 2)(3x^2 + 6x + 14) + 33
+        let p = [F::new(5), F::new(2), F::zero(), F::new(3)];
+        let (q, r) = synthetic_division(&p, &F::new(2));
+        assert_eq!(q, vec![F::new(14), F::new(6), F::new(3)]);
+        assert_eq!(r, F::new(33));
+        assert_eq!(synthetic_division(&[F::new(4)], &F::new(9)), (vec![], F::new(4)));
+        assert_eq!(synthetic_division::<F>(&[], &F::new(9)), (vec![], F::zero()));
+    }
+
+    #[test]
+    fn test_divide_by_x_n_minus_c() {
+        let mut rng = ChaCha20Rng::seed_from_u64(21);
+        for (n, q_deg) in [(1, 0), (4, 2), (8, 20), (16, 16)] {
+            let c = Goldilocks::random_with(&mut rng);
+            let q: Polynomial<Goldilocks> = generate_random_polynomial(q_deg, &mut rng);
+            let mut zerofier = vec![Goldilocks::zero(); n + 1];
+            zerofier[0] = -c;
+            zerofier[n] = Goldilocks::one();
+            let p = mul::multiply(&q.coefficients, &zerofier);
+
+            let quotient = divide_by_x_n_minus_c(&p, n, &c).unwrap();
+            assert_eq!(Polynomial::new(quotient), q);
+
+            let mut shifted = p.clone();
+            shifted[0] += Goldilocks::one();
+            assert_eq!(divide_by_x_n_minus_c(&shifted, n, &c), Err(PolynomialError::NotDivisible));
+        }
+        assert_eq!(divide_by_x_n_minus_c(&[Goldilocks::zero(); 3], 4, &Goldilocks::one()), Ok(vec![]));
+        assert!(divide_by_x_n_minus_c(&[Goldilocks::one()], 4, &Goldilocks::one()).is_err());
+    }
+
+    #[test]
+    fn test_divide_by_linear_factors() {
+        let mut rng = ChaCha20Rng::seed_from_u64(121);
+        let q: Polynomial<Goldilocks> = generate_random_polynomial(30, &mut rng);
+        let roots: Vec<Goldilocks> = (0..3).map(|_| Goldilocks::random_with(&mut rng)).collect();
+        let p = roots.iter().fold(q.clone(), |acc, r| &acc * &Polynomial::new(vec![-*r, Goldilocks::one()]));
+
+        assert_eq!(Polynomial::new(divide_by_linear_factors(&p.coefficients, &roots).unwrap()), q);
+        let not_a_root = roots[0] + Goldilocks::one();
+        assert_eq!(
+            divide_by_linear_factors(&p.coefficients, &[roots[1], not_a_root]),
+            Err(PolynomialError::NotDivisible)
+        );
+    }
+}
@@ -0,0 +1,195 @@
+//! Power-of-two multiplicative subgroups and their cosets as evaluation domains.
+//!
+//! A domain `D = { offset * omega^i : i < n }` is the root set of `x^n - offset^n`.
+//! Evaluating `sum c_j x^j` on it is the NTT of `c_j * offset^j`, so moving
+//! between coefficients and values on `D` costs `O(n log n)` either way.
+
+use crate::fields::{powers_table, Field};
+use crate::fri::CosetFri;
+use crate::polynomial::{ntt, Polynomial, PolynomialError};
+
+/// The coset `offset * <omega>` of the subgroup of order `size`; `offset = 1`
+/// gives the subgroup itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CosetDomain<F> {
+    offset: F,
+    omega: F,
+    size: usize,
+}
+
+impl<F: Field> CosetDomain<F> {
+    /// The subgroup of order `size`, generated by the field's canonical root of unity.
+    pub fn subgroup(size: usize) -> Result<Self, PolynomialError> {
+        Self::coset(F::one(), size)
+    }
+
+    /// The coset `offset * H` of the subgroup `H` of order `size`.
+    pub fn coset(offset: F, size: usize) -> Result<Self, PolynomialError> {
+        if !size.is_power_of_two() {
+            return Err(PolynomialError::DomainSizeNotPowerOfTwo(size));
+        }
+        if offset.is_zero() {
+            return Err(PolynomialError::ZeroOffset);
+        }
+        let omega =
+            F::two_adic_root_of_unity(size.trailing_zeros()).ok_or(PolynomialError::UnsupportedDomainSize(size))?;
+        Ok(CosetDomain { offset, omega, size })
+    }
+
+    /// Recognizes `points` as `points[0] * omega^i` for an `omega` of order
+    /// `points.len()`, a power of two. `None` for any other point set.
+    pub fn from_points(points: &[F]) -> Option<Self> {
+        let n = points.len();
+        if !n.is_power_of_two() || points[0].is_zero() {
+            return None;
+        }
+        let offset = points[0].clone();
+        let omega = if n == 1 { F::one() } else { points[1].clone() * offset.inverse() };
+        let mut expected = offset.clone();
+        for point in points {
+            if *point != expected {
+                return None;
+            }
+            expected *= omega.clone();
+        }
+        // The walk closes up (omega^n = 1), and omega^(n/2) = 1 would repeat points.
+        if expected != offset || (n > 1 && omega.pow(n as u64 / 2) == F::one()) {
+            return None;
+        }
+        Some(CosetDomain { offset, omega, size: n })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn offset(&self) -> &F {
+        &self.offset
+    }
+
+    pub fn omega(&self) -> &F {
+        &self.omega
+    }
+
+    /// `offset * omega^i`.
+    pub fn element(&self, i: usize) -> F {
+        self.offset.clone() * self.omega.pow(i as u64)
+    }
+
+    /// All the points, in order `offset * omega^i`.
+    pub fn elements(&self) -> Vec<F> {
+        let mut points = powers_table(&self.omega, self.size);
+        F::scale_slice(&mut points, &self.offset);
+        points
+    }
+
+    /// `offset^size`, so that the vanishing polynomial is `x^size - offset^size`.
+    pub fn vanishing_constant(&self) -> F {
+        self.offset.pow(self.size as u64)
+    }
+
+    /// `x^size - offset^size`.
+    pub fn vanishing_polynomial(&self) -> Polynomial<F> {
+        let mut coeffs = vec![F::zero(); self.size + 1];
+        coeffs[0] = -self.vanishing_constant();
+        coeffs[self.size] = F::one();
+        Polynomial::new(coeffs)
+    }
+
+    /// `[p(offset * omega^i)]` for every `i`, by one forward NTT. Polynomials of
+    /// degree `size` or more are first reduced modulo the vanishing polynomial.
+    pub fn evaluate(&self, p: &Polynomial<F>) -> Vec<F> {
+        // x^size = offset^size on the domain, so fold the high coefficients down.
+        let c = self.vanishing_constant();
+        let mut values = vec![F::zero(); self.size];
+        for (k, chunk) in p.coefficients.chunks(self.size).enumerate() {
+            let c_k = c.pow(k as u64);
+            for (v, coeff) in values.iter_mut().zip(chunk) {
+                *v += coeff.clone() * c_k.clone();
+            }
+        }
+        F::mul_slices(&mut values, &powers_table(&self.offset, self.size));
+        ntt::ntt(&mut values, &self.omega);
+        values
+    }
+
+    /// The polynomial of degree below `size` taking `values[i]` at
+    /// `offset * omega^i`, by one inverse NTT.
+    pub fn interpolate(&self, values: &[F]) -> Result<Polynomial<F>, PolynomialError> {
+        if values.len() != self.size {
+            return Err(PolynomialError::LengthMismatch { xs: self.size, ys: values.len() });
+        }
+        let mut coeffs = values.to_vec();
+        ntt::intt(&mut coeffs, &self.omega);
+        F::mul_slices(&mut coeffs, &powers_table(&self.offset.inverse(), self.size));
+        Ok(Polynomial::new(coeffs))
+    }
+}
+
+impl<F: Field> From<&CosetFri<F>> for CosetDomain<F> {
+    fn from(fri: &CosetFri<F>) -> Self {
+        CosetDomain { offset: fri.offset.clone(), omega: fri.omega.clone(), size: fri.domain_size }
+    }
+}
+
+#[cfg(test)]
+mod test_domain {
+    use super::*;
+    use crate::fields::{BabyBear, FieldElement, Goldilocks, Mersenne31};
+    use crate::polynomial::ops::generate_random_polynomial;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    #[test]
+    fn test_evaluate_interpolate_round_trip() {
+        let mut rng = ChaCha20Rng::seed_from_u64(230);
+        for size in [1, 2, 16, 1024] {
+            let subgroup = CosetDomain::<Goldilocks>::subgroup(size).unwrap();
+            let coset = CosetDomain::coset(Goldilocks::multiplicative_generator().unwrap(), size).unwrap();
+            for domain in [subgroup, coset] {
+                let p: Polynomial<Goldilocks> = generate_random_polynomial(size - 1, &mut rng);
+                let values = domain.evaluate(&p);
+                let expected: Vec<Goldilocks> = domain.elements().iter().map(|x| p.evaluate(*x)).collect();
+                assert_eq!(values, expected);
+                assert_eq!(domain.interpolate(&values).unwrap(), p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_evaluate_reduces_high_degree() {
+        let mut rng = ChaCha20Rng::seed_from_u64(231);
+        let domain = CosetDomain::coset(BabyBear::new(7), 32).unwrap();
+        let p: Polynomial<BabyBear> = generate_random_polynomial(100, &mut rng);
+        let expected: Vec<BabyBear> = domain.elements().iter().map(|x| p.evaluate(*x)).collect();
+        assert_eq!(domain.evaluate(&p), expected);
+        assert!(domain.elements().iter().all(|x| domain.vanishing_polynomial().evaluate(*x).is_zero()));
+    }
+
+    #[test]
+    fn test_from_points() {
+        let domain = CosetDomain::coset(Goldilocks::from_u64(5), 8).unwrap();
+        assert_eq!(CosetDomain::from_points(&domain.elements()), Some(domain.clone()));
+
+        let mut shuffled = domain.elements();
+        shuffled.swap(1, 2);
+        assert_eq!(CosetDomain::from_points(&shuffled), None);
+        // A geometric run whose ratio has order 4, not 8, repeats its points.
+        let omega4 = Goldilocks::two_adic_root_of_unity(2).unwrap();
+        let repeated: Vec<Goldilocks> = (0..8).map(|i| omega4.pow(i)).collect();
+        assert_eq!(CosetDomain::from_points(&repeated), None);
+        assert_eq!(CosetDomain::from_points(&[Goldilocks::one(); 3]), None);
+    }
+
+    #[test]
+    fn test_rejects_bad_sizes() {
+        type F = FieldElement<17>;
+        assert_eq!(CosetDomain::<F>::subgroup(6), Err(PolynomialError::DomainSizeNotPowerOfTwo(6)));
+        assert_eq!(CosetDomain::<F>::subgroup(32), Err(PolynomialError::UnsupportedDomainSize(32)));
+        assert_eq!(CosetDomain::<Mersenne31>::subgroup(4), Err(PolynomialError::UnsupportedDomainSize(4)));
+        assert_eq!(CosetDomain::coset(F::zero(), 4), Err(PolynomialError::ZeroOffset));
+
+        let fri = CosetFri::<F>::with_domain_size(8).unwrap();
+        assert_eq!(CosetDomain::from(&fri).elements(), fri.generate_coset_domain());
+    }
+}
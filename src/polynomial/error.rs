@@ -0,0 +1,26 @@
+use thiserror::Error;
+
+/// Errors reported by polynomial division, interpolation and evaluation domains.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PolynomialError {
+    #[error("the division leaves a non-zero remainder")]
+    NotDivisible,
+
+    #[error("mismatched x and y lengths: xs.len() = {xs}, ys.len() = {ys}")]
+    LengthMismatch { xs: usize, ys: usize },
+
+    #[error("interpolation points are not distinct")]
+    DuplicatePoint,
+
+    #[error("domain size {0} is not a power of two")]
+    DomainSizeNotPowerOfTwo(usize),
+
+    #[error("the field has no multiplicative subgroup of order {0}")]
+    UnsupportedDomainSize(usize),
+
+    #[error("the coset offset is zero")]
+    ZeroOffset,
+
+    #[error("division by a value that is zero at some point of the domain")]
+    DivisionByZero,
+}
@@ -0,0 +1,234 @@
+//! Polynomials in point-value form over a [`CosetDomain`].
+//!
+//! Pointwise arithmetic on the values is arithmetic on the underlying
+//! polynomials, as long as the result still has degree below the domain size:
+//! a product of two polynomials of degree below `n/2` is exact on a domain of
+//! size `n`. Extend to a larger coset first when it would not be.
+
+use std::ops::{Add, Div, Index, Mul, Sub};
+
+use crate::fields::Field;
+use crate::polynomial::{CosetDomain, Polynomial, PolynomialError};
+
+/// The values `f(offset * omega^i)` of some polynomial `f` on `domain`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Evaluations<F> {
+    values: Vec<F>,
+    domain: CosetDomain<F>,
+}
+
+impl<F: Field> Evaluations<F> {
+    /// Wraps `values`, one per domain point in order.
+    pub fn new(values: Vec<F>, domain: CosetDomain<F>) -> Result<Self, PolynomialError> {
+        if values.len() != domain.size() {
+            return Err(PolynomialError::LengthMismatch { xs: domain.size(), ys: values.len() });
+        }
+        Ok(Evaluations { values, domain })
+    }
+
+    /// Evaluates `p` on `domain` by one NTT.
+    pub fn from_polynomial(p: &Polynomial<F>, domain: &CosetDomain<F>) -> Self {
+        Evaluations { values: domain.evaluate(p), domain: domain.clone() }
+    }
+
+    /// The polynomial of degree below the domain size with these values, by one inverse NTT.
+    pub fn interpolate(&self) -> Polynomial<F> {
+        self.domain.interpolate(&self.values).expect("one value per domain point")
+    }
+
+    pub fn values(&self) -> &[F] {
+        &self.values
+    }
+
+    pub fn into_values(self) -> Vec<F> {
+        self.values
+    }
+
+    pub fn domain(&self) -> &CosetDomain<F> {
+        &self.domain
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Pointwise `1 / f`, with one batched inversion. Fails with
+    /// `DivisionByZero` if `f` vanishes at some point of the domain.
+    pub fn inverse(&self) -> Result<Self, PolynomialError> {
+        if self.values.iter().any(F::is_zero) {
+            return Err(PolynomialError::DivisionByZero);
+        }
+        let mut values = self.values.clone();
+        F::batch_inverse(&mut values);
+        Ok(Evaluations { values, domain: self.domain.clone() })
+    }
+
+    /// Pointwise `self / rhs`. Fails with `DivisionByZero` if `rhs` vanishes at
+    /// some point of the domain. Panics if the domains differ.
+    pub fn checked_div(&self, rhs: &Self) -> Result<Self, PolynomialError> {
+        self.check_same_domain(rhs);
+        let mut quotient = rhs.inverse()?;
+        F::mul_slices(&mut quotient.values, &self.values);
+        Ok(quotient)
+    }
+
+    /// Multiplies every value by `scalar`.
+    pub fn scale(&mut self, scalar: &F) {
+        F::scale_slice(&mut self.values, scalar);
+    }
+
+    /// The same polynomial on `target`, typically a larger coset: interpolate,
+    /// then evaluate there.
+    pub fn extend(&self, target: &CosetDomain<F>) -> Self {
+        Self::from_polynomial(&self.interpolate(), target)
+    }
+
+    /// Low-degree extension by `blowup` (a power of two) onto the coset of size
+    /// `blowup * len()` shifted by the field's multiplicative generator, which
+    /// keeps it disjoint from the subgroup.
+    pub fn low_degree_extension(&self, blowup: usize) -> Result<Self, PolynomialError> {
+        let size = self.len() * blowup;
+        if !blowup.is_power_of_two() {
+            return Err(PolynomialError::DomainSizeNotPowerOfTwo(size));
+        }
+        let offset = F::multiplicative_generator().ok_or(PolynomialError::UnsupportedDomainSize(size))?;
+        Ok(self.extend(&CosetDomain::coset(offset, size)?))
+    }
+
+    fn check_same_domain(&self, other: &Self) {
+        assert!(self.domain == other.domain, "domain mismatch: evaluations live on different domains");
+    }
+}
+
+impl<F> Index<usize> for Evaluations<F> {
+    type Output = F;
+
+    fn index(&self, i: usize) -> &F {
+        &self.values[i]
+    }
+}
+
+/// `Op` for owned and borrowed operands through one of the `Field` slice kernels.
+/// Panics if the domains differ.
+macro_rules! impl_pointwise_op {
+    ($trait:ident, $method:ident, $kernel:ident) => {
+        impl<F: Field> $trait for Evaluations<F> {
+            type Output = Self;
+
+            fn $method(mut self, rhs: Self) -> Self {
+                self.check_same_domain(&rhs);
+                F::$kernel(&mut self.values, &rhs.values);
+                self
+            }
+        }
+
+        impl<F: Field> $trait<&Evaluations<F>> for &Evaluations<F> {
+            type Output = Evaluations<F>;
+
+            fn $method(self, rhs: &Evaluations<F>) -> Evaluations<F> {
+                self.clone().$method(rhs.clone())
+            }
+        }
+    };
+}
+
+impl_pointwise_op!(Add, add, add_slices);
+impl_pointwise_op!(Sub, sub, sub_slices);
+impl_pointwise_op!(Mul, mul, mul_slices);
+
+/// Pointwise division; see [`Evaluations::checked_div`] for a non-panicking version.
+impl<F: Field> Div for Evaluations<F> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.checked_div(&rhs).expect("division by an evaluation vector with a zero value")
+    }
+}
+
+impl<F: Field> Div<&Evaluations<F>> for &Evaluations<F> {
+    type Output = Evaluations<F>;
+
+    fn div(self, rhs: &Evaluations<F>) -> Evaluations<F> {
+        self.checked_div(rhs).expect("division by an evaluation vector with a zero value")
+    }
+}
+
+impl<F: Field> Mul<F> for Evaluations<F> {
+    type Output = Self;
+
+    fn mul(mut self, scalar: F) -> Self {
+        self.scale(&scalar);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test_evaluations {
+    use super::*;
+    use crate::fields::{FieldElement, Goldilocks};
+    use crate::polynomial::ops::generate_random_polynomial;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    #[test]
+    fn test_pointwise_ops_match_coefficient_ops() {
+        let mut rng = ChaCha20Rng::seed_from_u64(25);
+        let domain = CosetDomain::<Goldilocks>::subgroup(64).unwrap();
+        let p: Polynomial<Goldilocks> = generate_random_polynomial(31, &mut rng);
+        let q: Polynomial<Goldilocks> = generate_random_polynomial(31, &mut rng);
+        let (ep, eq) = (Evaluations::from_polynomial(&p, &domain), Evaluations::from_polynomial(&q, &domain));
+
+        assert_eq!(ep.interpolate(), p);
+        assert_eq!((&ep + &eq).interpolate(), &p + &q);
+        assert_eq!((&ep - &eq).interpolate(), &p - &q);
+        assert_eq!((&ep * &eq).interpolate(), &p * &q);
+        assert_eq!((ep.clone() * Goldilocks::from_u64(3)).interpolate(), p.clone() * Goldilocks::from_u64(3));
+
+        // p * q / q = p wherever q does not vanish on the domain.
+        if eq.values().iter().all(|v| !v.is_zero()) {
+            assert_eq!(((&ep * &eq) / eq.clone()).interpolate(), p);
+        }
+    }
+
+    #[test]
+    fn test_division_by_zero_value() {
+        type F = FieldElement<17>;
+        let domain = CosetDomain::<F>::subgroup(4).unwrap();
+        let a = Evaluations::new(vec![F::new(1), F::new(2), F::new(3), F::new(4)], domain.clone()).unwrap();
+        let b = Evaluations::new(vec![F::new(1), F::zero(), F::new(3), F::new(4)], domain.clone()).unwrap();
+        assert_eq!(a.checked_div(&b), Err(PolynomialError::DivisionByZero));
+        assert_eq!(b.inverse(), Err(PolynomialError::DivisionByZero));
+        assert_eq!((&a / &a).values(), &[F::one(); 4]);
+        assert_eq!(
+            Evaluations::new(vec![F::one()], domain),
+            Err(PolynomialError::LengthMismatch { xs: 4, ys: 1 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "domain mismatch")]
+    fn test_mixing_domains_panics() {
+        let a = Evaluations::from_polynomial(&Polynomial::zero(), &CosetDomain::<Goldilocks>::subgroup(4).unwrap());
+        let b = Evaluations::from_polynomial(&Polynomial::zero(), &CosetDomain::<Goldilocks>::subgroup(8).unwrap());
+        let _ = a + b;
+    }
+
+    #[test]
+    fn test_low_degree_extension() {
+        let mut rng = ChaCha20Rng::seed_from_u64(125);
+        let domain = CosetDomain::<Goldilocks>::subgroup(32).unwrap();
+        let p: Polynomial<Goldilocks> = generate_random_polynomial(31, &mut rng);
+        let lde = Evaluations::from_polynomial(&p, &domain).low_degree_extension(4).unwrap();
+
+        assert_eq!(lde.len(), 128);
+        assert_eq!(*lde.domain().offset(), Goldilocks::multiplicative_generator().unwrap());
+        let expected: Vec<Goldilocks> = lde.domain().elements().iter().map(|x| p.evaluate(*x)).collect();
+        assert_eq!(lde.values(), expected);
+        assert_eq!(lde.interpolate(), p);
+        assert_eq!(lde[5], expected[5]);
+    }
+}
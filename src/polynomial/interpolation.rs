@@ -1,33 +1,33 @@
-use crate::fields::FieldElement;
-use crate::polynomial::Polynomial;
-use crate::{poly,fe,field};
+use crate::fields::Field;
+use crate::polynomial::{div, Polynomial, PolynomialError};
+use crate::poly;
 use rayon::prelude::*;
 // add ntt version latter
 
 
 
-pub fn gen_polynomial_from_roots<const M: u64>(roots: &[FieldElement<M>]) -> Polynomial<M> {
+pub fn gen_polynomial_from_roots<F: Field>(roots: &[F]) -> Polynomial<F> {
     if roots.is_empty() {
         return Polynomial::zero(); 
     }
 
     // Start with p(x) = 1
-    let mut p = poly![1];
+    let mut p = poly![F::one()];
 
     // Multiply p by (x - root) for each root
-    for &root in roots {
-        p = p * poly![-root, FieldElement::one()];
+    for root in roots {
+        p *= poly![-root.clone(), F::one()];
     }
 
     p
 }
 // /// nlogn but slower lmao 
-// pub fn polynomial_from_roots<const M: u64>(roots: &[FieldElement<M>]) -> Polynomial<M> {
+// pub fn polynomial_from_roots<F: Field>(roots: &[F]) -> Polynomial<F> {
 //     if roots.is_empty() {
 //         return Polynomial::zero();
 //     }
 //     if roots.len() == 1 {
-//         return poly![-roots[0], FieldElement::one()];
+//         return poly![-roots[0], F::one()];
 //     }
 
 //     let mid = roots.len() / 2;
@@ -38,134 +38,139 @@ pub fn gen_polynomial_from_roots<const M: u64>(roots: &[FieldElement<M>]) -> Pol
 // }
 
 
+/// `1 / ∏_{j != i} (x_i - x_j)` for every i, with a single batched inversion.
+/// A zero denominator means two of the points coincide.
+fn lagrange_denominator_inverses<F: Field>(xs: &[F]) -> Result<Vec<F>, PolynomialError> {
+    let mut denoms: Vec<F> = xs
+        .iter()
+        .enumerate()
+        .map(|(i, xi)| {
+            let mut denom = F::one();
+            for (j, xj) in xs.iter().enumerate() {
+                if i != j {
+                    denom *= xi.clone() - xj.clone();
+                }
+            }
+            denom
+        })
+        .collect();
+    if denoms.iter().any(F::is_zero) {
+        return Err(PolynomialError::DuplicatePoint);
+    }
+    F::batch_inverse(&mut denoms);
+    Ok(denoms)
+}
+
+/// `Z(x) / (x - x_i)` by synthetic division; the remainder is `Z(x_i)`, which
+/// must vanish.
+fn divide_vanishing_by_root<F: Field>(z: &Polynomial<F>, xi: &F) -> Result<Polynomial<F>, PolynomialError> {
+    let (quotient, remainder) = div::synthetic_division(&z.coefficients, xi);
+    if !remainder.is_zero() {
+        return Err(PolynomialError::NotDivisible);
+    }
+    Ok(Polynomial::new(quotient))
+}
+
+
 /// Return the Lagrange basis polynomials [L0, L1, ..., L_{n-1}],
 /// 
 ///    1) Let Vandermonde polynomial Z(x) = ∏ (x - x_j).
 ///    2) For each i, L_i(x) = [Z(x) / (x - x_i)] / denom_i,
 ///       where denom_i = ∏_{j != i} (x_i - x_j).
-pub fn gen_lagrange_polynomials<const M: u64>(xs: &[FieldElement<M>]) -> Vec<Polynomial<M>> {
+///
+/// Fails with `DuplicatePoint` if the `xs` are not distinct.
+pub fn gen_lagrange_polynomials<F: Field>(xs: &[F]) -> Result<Vec<Polynomial<F>>, PolynomialError> {
     let n = xs.len();
     if n == 0 {
-        return vec![];
+        return Ok(vec![]);
     }
     // 1)  Z(x) = ∏ (x - x_j).
-    let Z = gen_polynomial_from_roots(xs);
+    let z = gen_polynomial_from_roots(xs);
 
-    // 2) For each i, L_i(x) = (Z / (x - x_i)) * (1 / denom_i).
-    let mut lagrange_vec = Vec::with_capacity(n);
-
-    for i in 0..n {
-        // Compute denom_i = ∏_{j != i} (x_i - x_j).
-        let mut denom = FieldElement::one();
-        for j in 0..n {
-            if i == j { continue; }
-            denom *= xs[i] - xs[j];
-        }
-        let denom_inv = denom.inverse(); 
+    // 2) denom_i = ∏_{j != i} (x_i - x_j), inverted all at once.
+    let denom_invs = lagrange_denominator_inverses(xs)?;
 
+    // 3) For each i, L_i(x) = (Z / (x - x_i)) * (1 / denom_i).
+    let mut lagrange_vec = Vec::with_capacity(n);
 
-        let divisor = gen_polynomial_from_roots(&[xs[i]]); // (x - x_i)
-        let (mut li, rem) = Z.div_rem(&divisor);
-        if !rem.is_zero(){
-            panic!("Z(x) should be divisible by (x - x_i)");
-        }
- 
+    for (xi, denom_inv) in xs.iter().zip(denom_invs) {
+        let mut li = divide_vanishing_by_root(&z, xi)?;
         li.scalar_mul(denom_inv);
         lagrange_vec.push(li);
     }
 
-    lagrange_vec
+    Ok(lagrange_vec)
 }
 
-pub fn gen_lagrange_polynomials_parallel<const M: u64>(roots: &[FieldElement<M>]) -> Vec<Polynomial<M>> {
+/// [`gen_lagrange_polynomials`] with the basis polynomials computed on rayon.
+pub fn gen_lagrange_polynomials_parallel<F: Field>(roots: &[F]) -> Result<Vec<Polynomial<F>>, PolynomialError> {
     let n = roots.len();
     if n == 0 {
-        return vec![];
+        return Ok(vec![]);
     }
     // 1)  Z(x) = ∏ (x - x_j).
-    // 1)  Z(x) = ∏ (x - x_j).
-    let Z = gen_polynomial_from_roots(roots);
-        // Step 2: For each i, compute L_i(x) in parallel
-        (0..n)
-        .into_par_iter() 
-        .map(|i| {
-            // Compute denom_i
-            let mut denom = FieldElement::one();
-            for j in 0..n {
-                if i != j {
-                    denom *= roots[i] - roots[j];
-                }
-            }
-            let denom_inv = denom.inverse();
-
-            // Divide Z by (x - x_i)
-            let divisor = gen_polynomial_from_roots(&[roots[i]]);
-            let (mut li, rem) = Z.div_rem(&divisor);
-            if !rem.is_zero() {
-                panic!("Z(x) should be divisible by (x - x_i)");
-            }
+    let z = gen_polynomial_from_roots(roots);
+    let denom_invs = lagrange_denominator_inverses(roots)?;
+    // 2) For each i, compute L_i(x) in parallel
+    roots
+        .par_iter()
+        .zip(denom_invs)
+        .map(|(xi, denom_inv)| {
+            let mut li = divide_vanishing_by_root(&z, xi)?;
             li.scalar_mul(denom_inv);
-
-            li
+            Ok(li)
         })
         .collect()
-
-
-
 }
 
 /// Interpolate polynomial f of degree < n that satisfies
 /// f(xs[i]) = ys[i] for i = 0..n-1.
 ///
 /// Lagrange formula: f(x) = ∑ y_i * L_i(x).
-pub fn interpolate_lagrange_polynomials<const M: u64>(
-    xs: &[FieldElement<M>],
-    ys: &[FieldElement<M>]
-) -> Polynomial<M> {
+///
+/// This is O(n²) in time and memory; `Polynomial::interpolate` is the fast path
+/// and this stays as the reference it is checked against.
+///
+/// Fails with `LengthMismatch` if `xs` and `ys` differ in length and with
+/// `DuplicatePoint` if the `xs` are not distinct.
+pub fn interpolate_lagrange_polynomials<F: Field>(
+    xs: &[F],
+    ys: &[F]
+) -> Result<Polynomial<F>, PolynomialError> {
 
     if xs.len() != ys.len() {
-        panic!(
-            "Mismatched x and y lengths: xs.len() = {}, ys.len() = {}",
-            xs.len(),
-            ys.len()
-        );
+        return Err(PolynomialError::LengthMismatch { xs: xs.len(), ys: ys.len() });
     }
     let n = xs.len();
     if n == 0 {
         // Return zero polynomial if no points
-        return Polynomial::zero();
+        return Ok(Polynomial::zero());
     }
     // Compute the basis polynomials L_i(x).
     // use the pararell version 
     
-    let l = gen_lagrange_polynomials_parallel(xs);
+    let l = gen_lagrange_polynomials_parallel(xs)?;
 
     // Sum up: f(x) = Σ (ys[i] * L[i](x)).
     let mut acc = Polynomial::zero();
     for i in 0..n {
         let mut term = l[i].clone();
-        term.scalar_mul(ys[i]);
+        term.scalar_mul(ys[i].clone());
         // add to accumulator
         acc.add_assign(&term);
     }
-    acc
+    Ok(acc)
 }
 
     #[cfg(test)]
     mod test_interpol {
         use super::*;
+        use crate::fields::FieldElement;
+        use crate::{fe, field};
          // for collect_vec()
         
         
 
-        pub fn generate_random_polynomial<const M: u64>(degree: usize) -> Polynomial<M> {
-            let mut coeffs = Vec::with_capacity(degree + 1);
-            for _ in 0..=degree {
-                coeffs.push(FieldElement::<M>::random());
-            }
-            Polynomial::new(coeffs)
-        }
-    
         #[test]
         fn test_gen_polynomial_from_roots() {
 
@@ -192,7 +197,7 @@ pub fn interpolate_lagrange_polynomials<const M: u64>(
             ];
     
 
-        let lagrange_polys = gen_lagrange_polynomials(&xs);
+        let lagrange_polys = gen_lagrange_polynomials(&xs).unwrap();
         assert_eq!(lagrange_polys.len(), xs.len());
 
             for (i, &xi) in xs.iter().enumerate() {
@@ -263,10 +268,10 @@ fn test_gen_lagrange_poly2() {
         fe!(7, 5),fe!(7,6)
     ];
 
-    let lagrange_polynomials = gen_lagrange_polynomials(&x);
+    let lagrange_polynomials = gen_lagrange_polynomials(&x).unwrap();
     assert_eq!(lagrange_polynomials.len(), 4);
 
-    for (i, &xi) in x.iter().enumerate() {
+    for (i, &_xi) in x.iter().enumerate() {
         for (j, &xj) in x.iter().enumerate() {
             let eval = lagrange_polynomials[i].evaluate(xj);
             if i == j {
@@ -294,7 +299,7 @@ fn test_gen_lagrange_poly2() {
             fe!(7, 2),
             fe!(7, 3)
         ];
-        let result = interpolate_lagrange_polynomials(&x, &y);
+        let result = interpolate_lagrange_polynomials(&x, &y).unwrap();
 
         // Expected polynomial in GF(7) has coefficients: constant term = 5,
         // x term = 3, x^2 term = 1.
@@ -314,13 +319,13 @@ fn test_gen_lagrange_poly2() {
         ];
 
 
-        let lagrange_polys = gen_lagrange_polynomials_parallel(&x);
+        let lagrange_polys = gen_lagrange_polynomials_parallel(&x).unwrap();
 
 
         assert_eq!(lagrange_polys.len(), x.len());
 
         // Check that each L_i(x_j) == 1 if i == j, else 0
-        for (i, &xi) in x.iter().enumerate() {
+        for (i, &_xi) in x.iter().enumerate() {
             for (j, &xj) in x.iter().enumerate() {
                 let eval = lagrange_polys[i].evaluate(xj);
                 if i == j {
@@ -355,11 +360,11 @@ fn test_gen_lagrange_poly2() {
             fe!(7, 6),
         ];
 
-        let lagrange_polys = gen_lagrange_polynomials_parallel(&x);
+        let lagrange_polys = gen_lagrange_polynomials_parallel(&x).unwrap();
         assert_eq!(lagrange_polys.len(), x.len());
 
         // Same correctness check: L_i(x_j) == δ_{ij}
-        for (i, &xi) in x.iter().enumerate() {
+        for (i, &_xi) in x.iter().enumerate() {
             for (j, &xj) in x.iter().enumerate() {
                 let eval = lagrange_polys[i].evaluate(xj);
                 if i == j {
@@ -371,5 +376,40 @@ fn test_gen_lagrange_poly2() {
         }
     }
 
-}
 
+    #[test]
+    fn test_interpolation_errors() {
+        let x = vec![fe!(7, 2), fe!(7, 3), fe!(7, 2)];
+        let y = vec![fe!(7, 1), fe!(7, 2), fe!(7, 3)];
+        assert_eq!(interpolate_lagrange_polynomials(&x, &y), Err(PolynomialError::DuplicatePoint));
+        assert_eq!(gen_lagrange_polynomials(&x), Err(PolynomialError::DuplicatePoint));
+        assert_eq!(
+            interpolate_lagrange_polynomials(&x[..2], &y),
+            Err(PolynomialError::LengthMismatch { xs: 2, ys: 3 })
+        );
+    }
+
+    #[test]
+    fn test_fast_interpolation_matches_lagrange() {
+        use crate::fields::Goldilocks;
+        use crate::polynomial::CosetDomain;
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let mut rng = ChaCha20Rng::seed_from_u64(2323);
+        for n in [1, 7, 100] {
+            let xs: Vec<Goldilocks> = (0..n).map(|_| Goldilocks::random_with(&mut rng)).collect();
+            let ys: Vec<Goldilocks> = (0..n).map(|_| Goldilocks::random_with(&mut rng)).collect();
+            assert_eq!(Polynomial::interpolate(&xs, &ys), interpolate_lagrange_polynomials(&xs, &ys));
+        }
+
+        // Coset points take the inverse-NTT path.
+        let xs = CosetDomain::coset(Goldilocks::from_u64(3), 64).unwrap().elements();
+        let ys: Vec<Goldilocks> = (0..64).map(|_| Goldilocks::random_with(&mut rng)).collect();
+        assert_eq!(Polynomial::interpolate(&xs, &ys), interpolate_lagrange_polynomials(&xs, &ys));
+
+        let dup = vec![fe!(7, 1), fe!(7, 4), fe!(7, 1)];
+        assert_eq!(Polynomial::interpolate(&dup, &dup), Err(PolynomialError::DuplicatePoint));
+    }
+
+}
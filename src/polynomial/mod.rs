@@ -1,5 +1,18 @@
 pub mod ops;
 pub mod interpolation;
+pub mod barycentric;
+pub mod div;
+pub mod domain;
+pub mod error;
+pub mod evaluations;
+pub mod mul;
+pub mod ntt;
+pub mod tree;
 
+pub use barycentric::BarycentricEvaluator;
+pub use domain::CosetDomain;
+pub use error::PolynomialError;
+pub use evaluations::Evaluations;
 pub use ops::Polynomial;
+pub use tree::SubproductTree;
 
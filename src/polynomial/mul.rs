@@ -0,0 +1,166 @@
+//! Coefficient-vector multiplication used by `Polynomial::mul_assign`.
+//!
+//! [`multiply`] picks one of three methods by the length of the shorter factor:
+//! - below [`KARATSUBA_THRESHOLD`], the schoolbook loop;
+//! - from [`NTT_THRESHOLD`] on, the NTT, if the field has a large enough
+//!   two-adic root of unity;
+//! - otherwise Karatsuba, which recurses down to the schoolbook loop and runs
+//!   its sub-products on rayon from [`PARALLEL_THRESHOLD`] coefficients on.
+
+use crate::fields::Field;
+use crate::polynomial::ntt;
+
+/// Shorter-factor length from which [`multiply`] tries the NTT. In
+/// `benches/poly_ops.rs` (`MulCrossover_*`) the NTT overtakes the schoolbook loop
+/// at about 64 coefficients for Goldilocks and 128 for BabyBear.
+pub const NTT_THRESHOLD: usize = 128;
+
+/// Shorter-factor length below which Karatsuba hands over to the schoolbook loop.
+pub const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Longer-factor length from which Karatsuba computes its three sub-products in parallel.
+pub const PARALLEL_THRESHOLD: usize = 1 << 10;
+
+/// The O(n * m) product.
+pub fn schoolbook<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut product = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        if x.is_zero() {
+            continue;
+        }
+        for (j, y) in b.iter().enumerate() {
+            product[i + j] += x.clone() * y.clone();
+        }
+    }
+    product
+}
+
+/// Karatsuba product with the default [`KARATSUBA_THRESHOLD`].
+pub fn karatsuba<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    karatsuba_with_threshold(a, b, KARATSUBA_THRESHOLD)
+}
+
+/// Karatsuba product that switches to the schoolbook loop once the shorter
+/// factor has fewer than `threshold` coefficients (at least 2).
+pub fn karatsuba_with_threshold<F: Field>(a: &[F], b: &[F], threshold: usize) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut product = vec![F::zero(); a.len() + b.len() - 1];
+    karatsuba_into(&mut product, a, b, threshold.max(2));
+    product
+}
+
+/// `out[..a.len() + b.len() - 1] += a * b`.
+fn karatsuba_into<F: Field>(out: &mut [F], a: &[F], b: &[F], threshold: usize) {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.len() < threshold {
+        add_into(out, &schoolbook(a, b));
+        return;
+    }
+
+    let m = a.len().div_ceil(2);
+    let (a0, a1) = a.split_at(m);
+    if b.len() <= m {
+        // Unbalanced: a * b = a0 * b + x^m * (a1 * b).
+        karatsuba_into(out, a0, b, threshold);
+        karatsuba_into(&mut out[m..], a1, b, threshold);
+        return;
+    }
+
+    // (a0 + x^m a1)(b0 + x^m b1) = z0 + x^m ((a0 + a1)(b0 + b1) - z0 - z2) + x^2m z2
+    let (b0, b1) = b.split_at(m);
+    let a_sum = sum(a0, a1);
+    let b_sum = sum(b0, b1);
+    let product = |x: &[F], y: &[F]| karatsuba_with_threshold(x, y, threshold);
+    let (z0, (z2, mut z1)) = if a.len() >= PARALLEL_THRESHOLD {
+        rayon::join(
+            || product(a0, b0),
+            || rayon::join(|| product(a1, b1), || product(&a_sum, &b_sum)),
+        )
+    } else {
+        (product(a0, b0), (product(a1, b1), product(&a_sum, &b_sum)))
+    };
+    sub_into(&mut z1, &z0);
+    sub_into(&mut z1, &z2);
+
+    add_into(out, &z0);
+    add_into(&mut out[m..], &z1);
+    add_into(&mut out[2 * m..], &z2);
+}
+
+/// Coefficient-wise `lo + hi`, where `hi` is no longer than `lo`.
+fn sum<F: Field>(lo: &[F], hi: &[F]) -> Vec<F> {
+    let mut out = lo.to_vec();
+    F::add_slices(&mut out[..hi.len()], hi);
+    out
+}
+
+fn add_into<F: Field>(out: &mut [F], values: &[F]) {
+    F::add_slices(&mut out[..values.len()], values);
+}
+
+fn sub_into<F: Field>(out: &mut [F], values: &[F]) {
+    F::sub_slices(&mut out[..values.len()], values);
+}
+
+/// Product of two coefficient vectors (lowest degree first), using whichever
+/// method suits the sizes and the field.
+pub fn multiply<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let shorter = a.len().min(b.len());
+    if shorter >= NTT_THRESHOLD {
+        if let Some(product) = ntt::multiply(a, b) {
+            return product;
+        }
+    }
+    if shorter >= KARATSUBA_THRESHOLD {
+        return karatsuba(a, b);
+    }
+    schoolbook(a, b)
+}
+
+#[cfg(test)]
+mod test_mul {
+    use super::*;
+    use crate::fields::{FieldElement, Mersenne31};
+    use crate::polynomial::ops::generate_random_polynomial;
+    use crate::polynomial::Polynomial;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+
+    #[test]
+    fn test_karatsuba_matches_schoolbook() {
+        let mut rng = ChaCha20Rng::seed_from_u64(19);
+        for _ in 0..20 {
+            let (n, m) = (rng.gen_range(0..300), rng.gen_range(0..300));
+            let p: Polynomial<FieldElement<7>> = generate_random_polynomial(n, &mut rng);
+            let q: Polynomial<FieldElement<7>> = generate_random_polynomial(m, &mut rng);
+            let (a, b) = (&p.coefficients, &q.coefficients);
+            let expected = schoolbook(a, b);
+            for threshold in [2, 3, 8, KARATSUBA_THRESHOLD] {
+                assert_eq!(karatsuba_with_threshold(a, b, threshold), expected, "n={n} m={m} t={threshold}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parallel_karatsuba() {
+        // Mersenne31 has two-adicity 1, so `multiply` cannot use the NTT here.
+        let mut rng = ChaCha20Rng::seed_from_u64(1019);
+        let p: Polynomial<Mersenne31> = generate_random_polynomial(3 * PARALLEL_THRESHOLD, &mut rng);
+        let q: Polynomial<Mersenne31> = generate_random_polynomial(PARALLEL_THRESHOLD + 7, &mut rng);
+        let expected = schoolbook(&p.coefficients, &q.coefficients);
+        assert_eq!(multiply(&p.coefficients, &q.coefficients), expected);
+        assert_eq!((&p * &q).coefficients, expected);
+    }
+
+    #[test]
+    fn test_empty_factors() {
+        let a = vec![FieldElement::<7>::one(); 40];
+        assert!(karatsuba(&a, &[]).is_empty());
+        assert!(multiply(&[], &a).is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+//! Radix-2 number-theoretic transform over any `Field` with a large enough
+//! two-adic subgroup.
+//!
+//! `ntt(values, omega)` maps coefficients to evaluations at `1, omega, omega^2, ...`,
+//! where `omega` is a primitive root of unity of order `values.len()`; `intt`
+//! inverts it. Both work in place with an iterative Cooley-Tukey butterfly.
+
+use crate::fields::{powers_table, Field};
+
+/// Reorders `values` so that index `i` holds the entry at the bit-reversal of `i`.
+pub fn bit_reverse_permute<F>(values: &mut [F]) {
+    let n = values.len();
+    if n <= 2 {
+        return;
+    }
+    let shift = usize::BITS - n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> shift;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+/// In-place forward transform: `values[i] <- sum_j values[j] * omega^(i * j)`.
+/// Panics unless the length is a power of two.
+pub fn ntt<F: Field>(values: &mut [F], omega: &F) {
+    let n = values.len();
+    assert!(n.is_power_of_two(), "NTT length must be a power of two");
+    if n == 1 {
+        return;
+    }
+    bit_reverse_permute(values);
+
+    // twiddles[k] = omega^k; the stage of block size `len` uses every (n / len)-th one.
+    let twiddles = powers_table(omega, n / 2);
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let stride = n / len;
+        for block in values.chunks_mut(len) {
+            let (lo, hi) = block.split_at_mut(half);
+            for j in 0..half {
+                let t = hi[j].clone() * twiddles[j * stride].clone();
+                hi[j] = lo[j].clone() - t.clone();
+                lo[j] += t;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// In-place inverse of [`ntt`] for the same `omega`.
+pub fn intt<F: Field>(values: &mut [F], omega: &F) {
+    ntt(values, &omega.inverse());
+    let n_inv = F::from_u64(values.len() as u64).inverse();
+    F::scale_slice(values, &n_inv);
+}
+
+/// Product of two coefficient vectors through the NTT, or `None` when the field
+/// has no root of unity of order `(a.len() + b.len() - 1).next_power_of_two()`.
+pub fn multiply<F: Field>(a: &[F], b: &[F]) -> Option<Vec<F>> {
+    if a.is_empty() || b.is_empty() {
+        return Some(Vec::new());
+    }
+    let len = a.len() + b.len() - 1;
+    let n = len.next_power_of_two();
+    let omega = F::two_adic_root_of_unity(n.trailing_zeros())?;
+
+    let mut fa = a.to_vec();
+    fa.resize(n, F::zero());
+    let mut fb = b.to_vec();
+    fb.resize(n, F::zero());
+
+    ntt(&mut fa, &omega);
+    ntt(&mut fb, &omega);
+    F::mul_slices(&mut fa, &fb);
+    intt(&mut fa, &omega);
+    fa.truncate(len);
+    Some(fa)
+}
+
+#[cfg(test)]
+mod test_ntt {
+    use super::*;
+    use crate::fields::{BabyBear, BinaryField32b, FieldElement, Goldilocks};
+    use crate::polynomial::mul::schoolbook;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    #[test]
+    fn test_ntt_matches_direct_evaluation() {
+        type F = FieldElement<17>;
+        let omega = F::two_adic_root_of_unity(3).unwrap();
+        let coeffs: Vec<F> = (1..=8).map(F::new).collect();
+        let mut values = coeffs.clone();
+        ntt(&mut values, &omega);
+        for (i, value) in values.iter().enumerate() {
+            let x = omega.pow(i as u64);
+            let expected = coeffs.iter().rev().fold(F::zero(), |acc, c| acc * x + *c);
+            assert_eq!(*value, expected);
+        }
+        intt(&mut values, &omega);
+        assert_eq!(values, coeffs);
+    }
+
+    #[test]
+    fn test_multiply_matches_schoolbook() {
+        let mut rng = ChaCha20Rng::seed_from_u64(18);
+        for (n, m) in [(1, 1), (3, 5), (64, 64), (100, 37)] {
+            let a: Vec<Goldilocks> = (0..n).map(|_| Goldilocks::random_with(&mut rng)).collect();
+            let b: Vec<Goldilocks> = (0..m).map(|_| Goldilocks::random_with(&mut rng)).collect();
+            assert_eq!(multiply(&a, &b).unwrap(), schoolbook(&a, &b));
+
+            let a: Vec<BabyBear> = (0..n).map(|_| BabyBear::random_with(&mut rng)).collect();
+            let b: Vec<BabyBear> = (0..m).map(|_| BabyBear::random_with(&mut rng)).collect();
+            assert_eq!(multiply(&a, &b).unwrap(), schoolbook(&a, &b));
+        }
+    }
+
+    #[test]
+    fn test_missing_root_of_unity() {
+        // 2^4 = 16 is the largest power of two dividing 17 - 1.
+        let a = vec![FieldElement::<17>::one(); 9];
+        assert!(multiply(&a, &a).is_none());
+        let b = vec![BinaryField32b::one(); 2];
+        assert!(multiply(&b, &b).is_none());
+    }
+}
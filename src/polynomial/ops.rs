@@ -1,25 +1,26 @@
 use std::ops::{Add,AddAssign ,Sub,SubAssign, Mul, MulAssign, Div,DivAssign,Neg,Rem,RemAssign};
 use std::ops::{Fn, FnMut, FnOnce};
-use crate::polynomial::interpolation::interpolate_lagrange_polynomials;
+use crate::polynomial::{div, mul, tree, CosetDomain, PolynomialError, SubproductTree};
 
-use crate::fields::FieldElement;
+use crate::fields::{ExtensionOf, Field, FieldElement};
+use rand_core::RngCore;
 
 
 /// - `coefficients[i]` = coefficient for x^i term.
 #[derive(Clone, Debug)]
-pub struct Polynomial<const MODULUS: u64> {
-    pub coefficients: Vec<FieldElement<MODULUS>>,
+pub struct Polynomial<F> {
+    pub coefficients: Vec<F>,
     pub degree: isize,
 }
 
-impl<const MODULUS: u64> Polynomial<MODULUS> {
+impl<F: Field> Polynomial<F> {
 
 
     /// automatically trim trailing zeros and sets `self.degree`.
-    pub fn new(mut coeffs: Vec<FieldElement<MODULUS>>) -> Self {
+    pub fn new(mut coeffs: Vec<F>) -> Self {
         // Trim trailing zeros
-        while let Some(&last) = coeffs.last() {
-            if last == FieldElement::zero() {
+        while let Some(last) = coeffs.last() {
+            if last.is_zero() {
                 coeffs.pop();
             } else {
                 break;
@@ -45,8 +46,8 @@ impl<const MODULUS: u64> Polynomial<MODULUS> {
 
 
     fn update_degree(&mut self) {
-        while let Some(&last) = self.coefficients.last() {
-            if last == FieldElement::zero() {
+        while let Some(last) = self.coefficients.last() {
+            if last.is_zero() {
                 self.coefficients.pop();
             } else {
                 break;
@@ -64,20 +65,34 @@ impl<const MODULUS: u64> Polynomial<MODULUS> {
         self.degree == -1
     }
 
-    pub fn leading_coefficient(&self) -> Option<FieldElement<MODULUS>> {
+    pub fn leading_coefficient(&self) -> Option<F> {
         if self.is_zero() {
             None
         } else {
-            Some(self.coefficients[self.degree as usize])
+            Some(self.coefficients[self.degree as usize].clone())
         }
     }
 
     ///Horner's method: O(n).
-    pub fn evaluate(&self, x: FieldElement<MODULUS>) -> FieldElement<MODULUS> {
-        let mut result = FieldElement::zero();
+    pub fn evaluate(&self, x: F) -> F {
+        let mut result = F::zero();
         // Horner's method: result = (...((0 * x) + a_n)*x + a_{n-1})*x + ... + a_0
-        for &coef in self.coefficients.iter().rev() {
-            result = result * x + coef;
+        for coef in self.coefficients.iter().rev() {
+            result = result * x.clone() + coef.clone();
+        }
+        result
+    }
+
+    /// Evaluates at every point of `tree` by fast remaindering; see [`tree::evaluate_many`].
+    pub fn evaluate_many(&self, tree: &SubproductTree<F>) -> Vec<F> {
+        tree::evaluate_many(self, tree)
+    }
+
+    /// Evaluates at a point of an extension field, e.g. an out-of-domain sample.
+    pub fn evaluate_extension<E: ExtensionOf<F>>(&self, x: &E) -> E {
+        let mut result = E::zero();
+        for coef in self.coefficients.iter().rev() {
+            result = result * x.clone() + E::from(coef.clone());
         }
         result
     }
@@ -89,10 +104,10 @@ impl<const MODULUS: u64> Polynomial<MODULUS> {
             return;
         }
         let max_len = std::cmp::max(self.coefficients.len(), rhs.coefficients.len());
-        self.coefficients.resize(max_len, FieldElement::zero());
+        self.coefficients.resize(max_len, F::zero());
 
         for i in 0..rhs.coefficients.len() {
-            self.coefficients[i] += rhs.coefficients[i];
+            self.coefficients[i] += rhs.coefficients[i].clone();
         }
         self.update_degree();
     }
@@ -100,50 +115,79 @@ impl<const MODULUS: u64> Polynomial<MODULUS> {
     /// Subtract `rhs` polynomial from `self`, in-place.
     pub fn sub_assign(&mut self, rhs: &Self) {
         if rhs.is_zero() {
-            return; 
+            return;
         }
         let max_len = std::cmp::max(self.coefficients.len(), rhs.coefficients.len());
-        self.coefficients.resize(max_len, FieldElement::zero());
+        self.coefficients.resize(max_len, F::zero());
 
         for i in 0..rhs.coefficients.len() {
-            self.coefficients[i] -= rhs.coefficients[i];
+            self.coefficients[i] -= rhs.coefficients[i].clone();
         }
         self.update_degree();
     }
 
+    /// Schoolbook below [`mul::KARATSUBA_THRESHOLD`] coefficients, NTT from
+    /// [`mul::NTT_THRESHOLD`] on when the field has the roots of unity, and
+    /// Karatsuba otherwise; see [`mul::multiply`].
     pub fn mul_assign(&mut self, rhs: &Self) {
         if self.is_zero() {
-            return; 
+            return;
         }
         if rhs.is_zero() {
             *self = Self::zero();
             return;
         }
-        let new_len = self.coefficients.len() + rhs.coefficients.len() - 1;
-
-        let mut product = vec![FieldElement::zero(); new_len];
+        self.coefficients = mul::multiply(&self.coefficients, &rhs.coefficients);
+        self.update_degree();
+    }
 
-        // Naive nested loop
-        for (i, &a) in self.coefficients.iter().enumerate() {
-            if a == FieldElement::zero() {
-                continue;
-            }
-            for (j, &b) in rhs.coefficients.iter().enumerate() {
-                product[i + j] += a * b;
-            }
+    /// Returns (quotient, remainder). Long division for small operands,
+    /// Newton-iteration division (see [`div::div_rem_fast`]) once both the
+    /// quotient and the divisor reach [`div::FAST_DIV_THRESHOLD`] coefficients.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        if rhs.is_zero() {
+            panic!("Division by zero polynomial");
+        }
+        if self.degree < rhs.degree {
+            return (Self::zero(), self.clone());
+        }
+        let q_len = (self.degree - rhs.degree + 1) as usize;
+        if q_len.min(rhs.coefficients.len()) < div::FAST_DIV_THRESHOLD {
+            return self.div_rem_naive(rhs);
         }
+        let (quotient, remainder) = div::div_rem_fast(&self.coefficients, &rhs.coefficients);
+        (Polynomial::new(quotient), Polynomial::new(remainder))
+    }
 
-        self.coefficients = product;
-        self.update_degree();
+    /// Power-series inverse: the `g` of degree below `n` with `self * g = 1 mod x^n`.
+    /// `None` if the constant term is zero.
+    pub fn inverse_mod_xn(&self, n: usize) -> Option<Self> {
+        div::inverse_mod_xn(&self.coefficients, n).map(Polynomial::new)
     }
-    
+
+    /// Divides by `x - a` in linear time, returning the quotient and `self(a)`.
+    pub fn synthetic_div(&self, a: &F) -> (Self, F) {
+        let (quotient, remainder) = div::synthetic_division(&self.coefficients, a);
+        (Polynomial::new(quotient), remainder)
+    }
+
+    /// Exact division by the vanishing polynomial `x^n - c` in linear time.
+    pub fn div_by_vanishing(&self, n: usize, c: &F) -> Result<Self, PolynomialError> {
+        div::divide_by_x_n_minus_c(&self.coefficients, n, c).map(Polynomial::new)
+    }
+
+    /// Exact division by `∏ (x - root)` over a few `roots`.
+    pub fn div_by_roots(&self, roots: &[F]) -> Result<Self, PolynomialError> {
+        div::divide_by_linear_factors(&self.coefficients, roots).map(Polynomial::new)
+    }
+
     /// Returns (quotient, remainder) using naive polynomial long division.
-    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+    pub fn div_rem_naive(&self, rhs: &Self) -> (Self, Self) {
         if rhs.is_zero() {
             panic!("Division by zero polynomial");
         }
         if self.is_zero() || self.degree < rhs.degree {
-            return (Self::zero(), self.clone()); 
+            return (Self::zero(), self.clone());
         }
 
         let mut rem = self.coefficients.clone();
@@ -151,28 +195,28 @@ impl<const MODULUS: u64> Polynomial<MODULUS> {
 
         // quotient has length (deg(self)-deg(rhs)+1)
         let q_len = (self.degree - rhs.degree + 1) as usize;
-        let mut quotient = vec![FieldElement::zero(); q_len];
+        let mut quotient = vec![F::zero(); q_len];
 
-        let den_lead = rhs.coefficients[rhs.degree as usize]; 
+        let den_lead_inv = rhs.coefficients[rhs.degree as usize].inverse();
         let den_deg = rhs.degree;
 
         while rem_deg >= den_deg && rem_deg != -1 {
-            let lead_rem = rem[rem_deg as usize];
-            let ratio = lead_rem * den_lead.inverse();
+            let lead_rem = rem[rem_deg as usize].clone();
+            let ratio = lead_rem * den_lead_inv.clone();
 
 
             // shift for subtracting from remainder
             let shift = (rem_deg - den_deg) as usize;
-            quotient[shift] = quotient[shift] + ratio;
+            quotient[shift] = quotient[shift].clone() + ratio.clone();
 
             // subtract (ratio * x^shift * rhs) from remainder
             for i in 0..=den_deg as usize {
-                rem[i + shift] = rem[i + shift] - (ratio * rhs.coefficients[i]);
+                rem[i + shift] = rem[i + shift].clone() - (ratio.clone() * rhs.coefficients[i].clone());
             }
 
             // Trim new remainder's leading zeros
-            while let Some(&last) = rem.last() {
-                if last == FieldElement::zero() {
+            while let Some(last) = rem.last() {
+                if last.is_zero() {
                     rem.pop();
                 } else {
                     break;
@@ -190,28 +234,38 @@ impl<const MODULUS: u64> Polynomial<MODULUS> {
         (quot_poly, rem_poly)
     }
 
+    /// Formal derivative: `sum i * a_i x^(i-1)`.
+    pub fn derivative(&self) -> Self {
+        let coeffs = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| F::from_u64(i as u64) * c.clone())
+            .collect();
+        Polynomial::new(coeffs)
+    }
+
     /// Scalar multiplication in-place
-    pub fn scalar_mul(&mut self, scalar: FieldElement<MODULUS>) {
-            for coef in self.coefficients.iter_mut() {
-                *coef *= scalar;
-            }
+    pub fn scalar_mul(&mut self, scalar: F) {
+            F::scale_slice(&mut self.coefficients, &scalar);
         }
 
     /// Scalar division in-place
-    pub fn scalar_div(&mut self, scalar: FieldElement<MODULUS>) {
-        if scalar == FieldElement::<MODULUS>::zero() {
+    pub fn scalar_div(&mut self, scalar: F) {
+        if scalar.is_zero() {
             panic!("Division by zero in a finite field is not allowed.");
         }
-        
+
         let scalar_inv = scalar.inverse();
         for coef in self.coefficients.iter_mut() {
-            *coef *= scalar_inv;
+            *coef *= scalar_inv.clone();
         }
     }
 
     /// Compose `self` with `other`: return `self(other)`.
     /// i.e. p(q) = sum_{i=0}^degree( coeff[i] * [q(x)]^i ).
-    pub fn compose(&self, other: &Polynomial<MODULUS>) -> Polynomial<MODULUS> {
+    pub fn compose(&self, other: &Polynomial<F>) -> Polynomial<F> {
         if self.is_zero() {
             return Polynomial::zero();
         }
@@ -220,30 +274,43 @@ impl<const MODULUS: u64> Polynomial<MODULUS> {
         //   p(x) = a_n x^n + ... + a_1 x + a_0
         //   p(q) = (((0 * q) + a_n)*q + a_{n-1})*q + ... + a_0
         let mut result = Polynomial::zero();
-        for &coeff in self.coefficients.iter().rev() {
+        for coeff in self.coefficients.iter().rev() {
             // result = result * other + coeff
             if !result.is_zero() {
                 let mut temp = result.clone();
                 temp.mul_assign(other);
                 // add constant 'coeff'
-                temp.add_assign(&Polynomial::new(vec![coeff]));
+                temp.add_assign(&Polynomial::new(vec![coeff.clone()]));
                 result = temp;
             } else {
                 // When result=0, result*q + coeff = [const polynomial with 'coeff']
-                result = Polynomial::new(vec![coeff]);
+                result = Polynomial::new(vec![coeff.clone()]);
             }
         }
         result
     }
 
-    pub fn interpolate(xs: &[FieldElement<MODULUS>], ys: &[FieldElement<MODULUS>]) -> Self {
-        interpolate_lagrange_polynomials(xs, ys)
+    /// The polynomial of degree below `xs.len()` through `(xs[i], ys[i])`.
+    ///
+    /// Points forming a subgroup or coset in order (`xs[i] = xs[0] * omega^i`,
+    /// see [`CosetDomain::from_points`]) go through one inverse NTT; any other
+    /// points through [`SubproductTree::interpolate`]. Fails with `LengthMismatch`
+    /// or `DuplicatePoint`. `interpolation::interpolate_lagrange_polynomials` is the quadratic
+    /// reference implementation.
+    pub fn interpolate(xs: &[F], ys: &[F]) -> Result<Self, PolynomialError> {
+        if xs.len() != ys.len() {
+            return Err(PolynomialError::LengthMismatch { xs: xs.len(), ys: ys.len() });
+        }
+        if let Some(domain) = CosetDomain::from_points(xs) {
+            return domain.interpolate(ys);
+        }
+        SubproductTree::new(xs).interpolate(ys)
     }
 }
 
-///trait
+// trait impls
 
-impl<const M: u64> Add for Polynomial<M> {
+impl<F: Field> Add for Polynomial<F> {
     type Output = Self;
 
     fn add(mut self, rhs: Self) -> Self::Output {
@@ -252,31 +319,31 @@ impl<const M: u64> Add for Polynomial<M> {
     }
 }
 
-impl<const M: u64> Add<&Polynomial<M>> for Polynomial<M> {
+impl<F: Field> Add<&Polynomial<F>> for Polynomial<F> {
     type Output = Self;
 
-    fn add(mut self, rhs: &Polynomial<M>) -> Self::Output {
+    fn add(mut self, rhs: &Polynomial<F>) -> Self::Output {
         self.add_assign(rhs);
         self
     }
 }
 
-impl<const M: u64> Add<Polynomial<M>> for &Polynomial<M> {
-    type Output = Polynomial<M>;
+impl<F: Field> Add<Polynomial<F>> for &Polynomial<F> {
+    type Output = Polynomial<F>;
 
-    fn add(self, rhs: Polynomial<M>) -> Polynomial<M> {
+    fn add(self, rhs: Polynomial<F>) -> Polynomial<F> {
         self.clone() + rhs
     }
 }
 
-impl<const M: u64> Add<&Polynomial<M>> for &Polynomial<M> {
-    type Output = Polynomial<M>;
+impl<F: Field> Add<&Polynomial<F>> for &Polynomial<F> {
+    type Output = Polynomial<F>;
 
-    fn add(self, rhs: &Polynomial<M>) -> Polynomial<M> {
+    fn add(self, rhs: &Polynomial<F>) -> Polynomial<F> {
         self.clone() + rhs.clone()
     }
 }
-impl<const M: u64> Sub for Polynomial<M> {
+impl<F: Field> Sub for Polynomial<F> {
     type Output = Self;
 
     fn sub(mut self, rhs: Self) -> Self::Output {
@@ -285,45 +352,45 @@ impl<const M: u64> Sub for Polynomial<M> {
     }
 }
 
-impl<const M: u64> Sub<&Polynomial<M>> for Polynomial<M> {
+impl<F: Field> Sub<&Polynomial<F>> for Polynomial<F> {
     type Output = Self;
 
-    fn sub(mut self, rhs: &Polynomial<M>) -> Self::Output {
+    fn sub(mut self, rhs: &Polynomial<F>) -> Self::Output {
         self.sub_assign(rhs);
         self
     }
 }
 
-impl<const M: u64> Sub<Polynomial<M>> for &Polynomial<M> {
-    type Output = Polynomial<M>;
+impl<F: Field> Sub<Polynomial<F>> for &Polynomial<F> {
+    type Output = Polynomial<F>;
 
-    fn sub(self, rhs: Polynomial<M>) -> Polynomial<M> {
+    fn sub(self, rhs: Polynomial<F>) -> Polynomial<F> {
         self.clone() - rhs
     }
 }
 
-impl<const M: u64> Sub<&Polynomial<M>> for &Polynomial<M> {
-    type Output = Polynomial<M>;
+impl<F: Field> Sub<&Polynomial<F>> for &Polynomial<F> {
+    type Output = Polynomial<F>;
 
-    fn sub(self, rhs: &Polynomial<M>) -> Polynomial<M> {
+    fn sub(self, rhs: &Polynomial<F>) -> Polynomial<F> {
         self.clone() - rhs.clone()
     }
 }
-impl<const M: u64> AddAssign for Polynomial<M> {
+impl<F: Field> AddAssign for Polynomial<F> {
     fn add_assign(&mut self, rhs: Self) {
         self.add_assign(&rhs);
     }
 }
 
-impl<const M: u64> SubAssign for Polynomial<M> {
+impl<F: Field> SubAssign for Polynomial<F> {
     fn sub_assign(&mut self, rhs: Self) {
         self.sub_assign(&rhs);
     }
 }
 
 
-// Implement Mul for Polynomial<M> and &Polynomial<M>
-impl<const M: u64> Mul for Polynomial<M> {
+// Implement Mul for Polynomial<F> and &Polynomial<F>
+impl<F: Field> Mul for Polynomial<F> {
     type Output = Self;
 
     fn mul(mut self, rhs: Self) -> Self::Output {
@@ -332,84 +399,84 @@ impl<const M: u64> Mul for Polynomial<M> {
     }
 }
 
-impl<const M: u64> Mul<&Polynomial<M>> for Polynomial<M> {
+impl<F: Field> Mul<&Polynomial<F>> for Polynomial<F> {
     type Output = Self;
 
-    fn mul(mut self, rhs: &Polynomial<M>) -> Self::Output {
+    fn mul(mut self, rhs: &Polynomial<F>) -> Self::Output {
         self.mul_assign(rhs);
         self
     }
 }
 
-impl<const M: u64> Mul<Polynomial<M>> for &Polynomial<M> {
-    type Output = Polynomial<M>;
+impl<F: Field> Mul<Polynomial<F>> for &Polynomial<F> {
+    type Output = Polynomial<F>;
 
-    fn mul(self, rhs: Polynomial<M>) -> Polynomial<M> {
+    fn mul(self, rhs: Polynomial<F>) -> Polynomial<F> {
         self.clone() * rhs
     }
 }
 
-impl<const M: u64> Mul<&Polynomial<M>> for &Polynomial<M> {
-    type Output = Polynomial<M>;
+impl<F: Field> Mul<&Polynomial<F>> for &Polynomial<F> {
+    type Output = Polynomial<F>;
 
-    fn mul(self, rhs: &Polynomial<M>) -> Polynomial<M> {
+    fn mul(self, rhs: &Polynomial<F>) -> Polynomial<F> {
         self.clone() * rhs.clone()
     }
 }
 
-// Implement Mul for FieldElement<M>
-impl<const M: u64> Mul<FieldElement<M>> for Polynomial<M> {
+// Implement Mul for the field scalar
+impl<F: Field> Mul<F> for Polynomial<F> {
     type Output = Self;
 
-    fn mul(mut self, scalar: FieldElement<M>) -> Self::Output {
+    fn mul(mut self, scalar: F) -> Self::Output {
         self.scalar_mul(scalar);
         self
     }
 }
 
-impl<const M: u64> Mul<FieldElement<M>> for &Polynomial<M> {
-    type Output = Polynomial<M>;
+impl<F: Field> Mul<F> for &Polynomial<F> {
+    type Output = Polynomial<F>;
 
-    fn mul(self, scalar: FieldElement<M>) -> Polynomial<M> {
+    fn mul(self, scalar: F) -> Polynomial<F> {
         self.clone() * scalar
     }
 }
 
 
 
-impl<const M: u64> MulAssign<FieldElement<M>> for Polynomial<M> {
-    fn mul_assign(&mut self, scalar: FieldElement<M>) {
+impl<F: Field> MulAssign<F> for Polynomial<F> {
+    fn mul_assign(&mut self, scalar: F) {
         self.scalar_mul(scalar);
     }
 }
 
-impl<const M: u64> MulAssign<Polynomial<M>> for Polynomial<M> {
-    fn mul_assign(&mut self, rhs: Polynomial<M>) {
+impl<F: Field> MulAssign<Polynomial<F>> for Polynomial<F> {
+    fn mul_assign(&mut self, rhs: Polynomial<F>) {
         *self = self.clone() * rhs; // Use your existing `Mul` implementation
     }
 }
 
-impl<const M: u64> Neg for Polynomial<M> {
+impl<F: Field> Neg for Polynomial<F> {
     type Output = Self;
     fn neg(mut self) -> Self::Output {
         for c in self.coefficients.iter_mut() {
-            *c = -*c;
+            *c = -c.clone();
         }
         self
     }
 }
-impl<const M: u64> Neg for &Polynomial<M> {
-    type Output = Polynomial<M>;
+impl<F: Field> Neg for &Polynomial<F> {
+    type Output = Polynomial<F>;
 
     fn neg(self) -> Self::Output {
         let mut neg_poly = self.clone();
         for coef in neg_poly.coefficients.iter_mut() {
-            *coef = -*coef;
+            *coef = -coef.clone();
         }
         neg_poly
     }
 }
-impl<const M: u64> Div for Polynomial<M> {
+impl<F: Field> Div for Polynomial<F> {
     type Output = Self;
     fn div(self, rhs: Self) -> Self::Output {
         let (q, r) = self.div_rem(&rhs);
@@ -421,22 +488,22 @@ impl<const M: u64> Div for Polynomial<M> {
 }
 
 
-impl<const M: u64> Div<FieldElement<M>> for Polynomial<M> {
+impl<F: Field> Div<F> for Polynomial<F> {
     type Output = Self;
-    fn div(mut self, scalar: FieldElement<M>) -> Self::Output {
+    fn div(mut self, scalar: F) -> Self::Output {
         self.div_assign(scalar);
         self
     }
 }
 
-impl<const M: u64> DivAssign<FieldElement<M>> for Polynomial<M> {
-    fn div_assign(&mut self, scalar: FieldElement<M>) {
+impl<F: Field> DivAssign<F> for Polynomial<F> {
+    fn div_assign(&mut self, scalar: F) {
         self.scalar_div(scalar);
     }
 }
 
 
-impl<const M: u64> PartialEq for Polynomial<M> {
+impl<F: Field> PartialEq for Polynomial<F> {
     fn eq(&self, other: &Self) -> bool {
         if self.degree != other.degree {
             return false;
@@ -454,21 +521,23 @@ impl<const M: u64> PartialEq for Polynomial<M> {
 }
 
 // Eq deries from PartialEq
-impl<const M: u64> Eq for Polynomial<M> {}
+impl<F: Field> Eq for Polynomial<F> {}
 
-impl<const M: u64> Mul<Polynomial<M>> for FieldElement<M> {
-    type Output = Polynomial<M>;
-    
-    fn mul(self, mut poly: Polynomial<M>) -> Polynomial<M> {
+// `impl Mul<Polynomial<F>> for F` is not allowed by the orphan rules, so the
+// scalar-on-the-left form is provided for the prime field only.
+impl<const M: u64> Mul<Polynomial<FieldElement<M>>> for FieldElement<M> {
+    type Output = Polynomial<FieldElement<M>>;
+
+    fn mul(self, mut poly: Polynomial<FieldElement<M>>) -> Polynomial<FieldElement<M>> {
         for coef in poly.coefficients.iter_mut() {
-            *coef *= self; // 
+            *coef *= self; //
         }
         poly
     }
 }
 
 
-impl<const MODULUS: u64> Rem for Polynomial<MODULUS> {
+impl<F: Field> Rem for Polynomial<F> {
     type Output = Self;
 
     fn rem(self, rhs: Self) -> Self::Output {
@@ -479,7 +548,7 @@ impl<const MODULUS: u64> Rem for Polynomial<MODULUS> {
 }
 
 
-impl<const MODULUS: u64> RemAssign for Polynomial<MODULUS> {
+impl<F: Field> RemAssign for Polynomial<F> {
     fn rem_assign(&mut self, rhs: Self) {
         let (_, remainder) = self.clone().div_rem(&rhs);
         *self = remainder;
@@ -487,62 +556,66 @@ impl<const MODULUS: u64> RemAssign for Polynomial<MODULUS> {
 }
 
 // some nightly rust problems
-impl<const M: u64> FnOnce<(Polynomial<M>,)> for Polynomial<M> {
-    type Output = Polynomial<M>;
+impl<F: Field> FnOnce<(Polynomial<F>,)> for Polynomial<F> {
+    type Output = Polynomial<F>;
 
-    extern "rust-call" fn call_once(self, args: (Polynomial<M>,)) -> Self::Output {
+    extern "rust-call" fn call_once(self, args: (Polynomial<F>,)) -> Self::Output {
         self.compose(&args.0)
     }
 }
 
-impl<const M: u64> FnMut<(Polynomial<M>,)> for Polynomial<M> {
-    extern "rust-call" fn call_mut(&mut self, args: (Polynomial<M>,)) -> Self::Output {
+impl<F: Field> FnMut<(Polynomial<F>,)> for Polynomial<F> {
+    extern "rust-call" fn call_mut(&mut self, args: (Polynomial<F>,)) -> Self::Output {
         // FnMut means self is mutable reference
         self.compose(&args.0)
     }
 }
 
-impl<const M: u64> Fn<(Polynomial<M>,)> for Polynomial<M> {
-    extern "rust-call" fn call(&self, args: (Polynomial<M>,)) -> Self::Output {
+impl<F: Field> Fn<(Polynomial<F>,)> for Polynomial<F> {
+    extern "rust-call" fn call(&self, args: (Polynomial<F>,)) -> Self::Output {
         // Fn means self is an immutable reference
         self.compose(&args.0)
     }
 }
 
-impl<const M: u64, T: Into<FieldElement<M>>> FnOnce<(T,)> for Polynomial<M> {
-    type Output = FieldElement<M>;
+// With a generic field an `Into<F>` argument would overlap with the
+// composition impls above, so evaluation takes the field element directly.
+impl<F: Field> FnOnce<(F,)> for Polynomial<F> {
+    type Output = F;
 
-    extern "rust-call" fn call_once(self, args: (T,)) -> Self::Output {
-        self.evaluate(args.0.into())
+    extern "rust-call" fn call_once(self, args: (F,)) -> Self::Output {
+        self.evaluate(args.0)
     }
 }
 
-impl<const M: u64, T: Into<FieldElement<M>>> FnMut<(T,)> for Polynomial<M> {
-    extern "rust-call" fn call_mut(&mut self, args: (T,)) -> Self::Output {
-        self.evaluate(args.0.into())
+impl<F: Field> FnMut<(F,)> for Polynomial<F> {
+    extern "rust-call" fn call_mut(&mut self, args: (F,)) -> Self::Output {
+        self.evaluate(args.0)
     }
 }
 
-impl<const M: u64, T: Into<FieldElement<M>>> Fn<(T,)> for Polynomial<M> {
-    extern "rust-call" fn call(&self, args: (T,)) -> Self::Output {
-        self.evaluate(args.0.into())
+impl<F: Field> Fn<(F,)> for Polynomial<F> {
+    extern "rust-call" fn call(&self, args: (F,)) -> Self::Output {
+        self.evaluate(args.0)
     }
 }
 
 
 // imp iterator
 
-impl<const M: u64> FromIterator<FieldElement<M>> for Polynomial<M> {
-    fn from_iter<T: IntoIterator<Item = FieldElement<M>>>(iter: T) -> Self {
-        let coeffs: Vec<FieldElement<M>> = iter.into_iter().collect();
+impl<F: Field> FromIterator<F> for Polynomial<F> {
+    fn from_iter<T: IntoIterator<Item = F>>(iter: T) -> Self {
+        let coeffs: Vec<F> = iter.into_iter().collect();
         Self::new(coeffs)
     }
 }
 
-pub fn generate_random_polynomial<const M: u64>(degree: usize) -> Polynomial<M> {
+/// Random polynomial with `degree + 1` coefficients drawn from `rng`; pass a
+/// seeded RNG (e.g. `ChaCha20Rng`) to make tests and benchmarks reproducible.
+pub fn generate_random_polynomial<F: Field, R: RngCore + ?Sized>(degree: usize, rng: &mut R) -> Polynomial<F> {
     let mut coeffs = Vec::with_capacity(degree + 1);
     for _ in 0..=degree {
-        coeffs.push(FieldElement::<M>::random());
+        coeffs.push(F::random_with(rng));
     }
     Polynomial::new(coeffs)
 }
@@ -551,26 +624,26 @@ pub fn generate_random_polynomial<const M: u64>(degree: usize) -> Polynomial<M>
 #[cfg(test)]
 mod test_polynomials {
     use super::*;
-    use rand::Rng;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
 
 
 
 
-    fn generate_random_polynomial(degree: usize) -> Polynomial<7> {
-        let mut coeffs: Vec<FieldElement<7>> =
-            (0..degree).map(|_| FieldElement::random()).collect();
-        let mut leading = FieldElement::zero();
-        while leading == FieldElement::zero() {
-            leading = FieldElement::random();
+    /// Random polynomial of exactly `degree` (the leading coefficient is resampled until non-zero).
+    fn generate_random_polynomial(degree: usize, rng: &mut ChaCha20Rng) -> Polynomial<FieldElement<7>> {
+        loop {
+            let poly: Polynomial<FieldElement<7>> = super::generate_random_polynomial(degree, rng);
+            if poly.degree == degree as isize {
+                return poly;
+            }
         }
-        coeffs.push(leading);
-        Polynomial::new(coeffs)
     }
 
  
     #[test]
     fn test_zero_polynomial() {
-        let poly: Polynomial<7> = Polynomial::zero();
+        let poly: Polynomial<FieldElement<7>> = Polynomial::zero();
         assert_eq!(poly.degree, -1);
         assert!(poly.is_zero());
     }
@@ -689,14 +762,19 @@ mod test_polynomials {
 
     #[test]
     fn test_poly_random_generation() {
-        let poly = generate_random_polynomial(5);
+        let poly = generate_random_polynomial(5, &mut ChaCha20Rng::seed_from_u64(5));
         assert_eq!(poly.degree, 5);
+
+        // Same seed, same polynomial.
+        let a: Polynomial<FieldElement<7>> = super::generate_random_polynomial(8, &mut ChaCha20Rng::seed_from_u64(1));
+        let b: Polynomial<FieldElement<7>> = super::generate_random_polynomial(8, &mut ChaCha20Rng::seed_from_u64(1));
+        assert_eq!(a, b);
     }
 
     #[test]
     fn test_create_with_empty_coeffs() {
         // Creating a polynomial with an empty vector should result in a zero polynomial.
-        let poly: Polynomial<7> = Polynomial::new(vec![]);
+        let poly: Polynomial<FieldElement<7>> = Polynomial::new(vec![]);
         
         assert!(poly.is_zero(), "A polynomial created with empty coefficients should be zero.");
         assert_eq!(poly.coefficients.len(), 0, "Zero polynomial should have no coefficients.");
@@ -722,7 +800,7 @@ mod test_polynomials {
     
     #[test]
     fn test_is_zero() {
-        let zero_poly = Polynomial::<7>::zero();
+        let zero_poly = Polynomial::<FieldElement<7>>::zero();
         assert!(zero_poly.is_zero(), "Zero polynomial should return true for is_zero().");
     
         let non_zero_poly = Polynomial::new(vec![FieldElement::<7>::new(0), FieldElement::<7>::new(1)]);
@@ -738,7 +816,7 @@ mod test_polynomials {
             "Leading coefficient should be the highest-degree term."
         );
     
-        let zero_poly = Polynomial::<7>::zero();
+        let zero_poly = Polynomial::<FieldElement<7>>::zero();
         assert_eq!(
             zero_poly.leading_coefficient(),
             None,
@@ -814,6 +892,23 @@ fn test_mul_assign_polynomial() {
 }
 
 
+#[test]
+fn test_mul_above_ntt_threshold() {
+    let mut rng = ChaCha20Rng::seed_from_u64(18);
+    let n = 2 * mul::NTT_THRESHOLD;
+    // Goldilocks takes the NTT path; FieldElement<7> has no root of order 2n and falls back.
+    let p: Polynomial<crate::fields::Goldilocks> = super::generate_random_polynomial(n, &mut rng);
+    let q: Polynomial<crate::fields::Goldilocks> = super::generate_random_polynomial(n + 3, &mut rng);
+    let product = &p * &q;
+    assert_eq!(product.coefficients, mul::schoolbook(&p.coefficients, &q.coefficients));
+
+    let p = generate_random_polynomial(n, &mut rng);
+    let q = generate_random_polynomial(n, &mut rng);
+    let product = &p * &q;
+    assert_eq!(product.degree, 2 * n as isize);
+    assert_eq!(product.coefficients, mul::schoolbook(&p.coefficients, &q.coefficients));
+}
+
 #[test]
 fn test_poly_scalar_division() {
     let coeffs = vec![FieldElement::<7>::new(2), FieldElement::<7>::new(4)];
@@ -939,7 +1034,7 @@ fn test_poly_div_rem_nontrivial() {
 
     #[test]
     fn test_neg() {
-        let p = Polynomial::<7>::new(vec![
+        let p = Polynomial::<FieldElement<7>>::new(vec![
             FieldElement::<7>::new(1),
             FieldElement::<7>::new(2),
         ]);
@@ -951,11 +1046,11 @@ fn test_poly_div_rem_nontrivial() {
 
     #[test]
     fn test_partial_eq_diff_length() {
-        let p = Polynomial::<7>::new(vec![
+        let p = Polynomial::<FieldElement<7>>::new(vec![
             FieldElement::<7>::new(1),
             FieldElement::<7>::new(2),
         ]);
-        let q = Polynomial::<7>::new(vec![
+        let q = Polynomial::<FieldElement<7>>::new(vec![
             FieldElement::<7>::new(1),
             FieldElement::<7>::new(2),
             FieldElement::<7>::new(3),
@@ -967,11 +1062,11 @@ fn test_poly_div_rem_nontrivial() {
 
     #[test]
     fn test_partial_eq_diff_coeff() {
-        let p = Polynomial::<7>::new(vec![
+        let p = Polynomial::<FieldElement<7>>::new(vec![
             FieldElement::<7>::new(1),
             FieldElement::<7>::new(2),
         ]);
-        let q = Polynomial::<7>::new(vec![
+        let q = Polynomial::<FieldElement<7>>::new(vec![
             FieldElement::<7>::new(1),
             FieldElement::<7>::new(3),
         ]);
@@ -982,11 +1077,11 @@ fn test_poly_div_rem_nontrivial() {
 //     #[test]
 #[test]
 fn test_compose_with_zero() {
-    let p = Polynomial::<7>::new(vec![
+    let p = Polynomial::<FieldElement<7>>::new(vec![
         FieldElement::<7>::new(1),
         FieldElement::<7>::new(1),
     ]);
-    let zero_poly = Polynomial::<7>::zero();
+    let zero_poly = Polynomial::<FieldElement<7>>::zero();
     let result = p.compose(&zero_poly);
     assert_eq!(result.degree, 0, "Composing with zero should yield a constant polynomial.");
     assert_eq!(result.coefficients.len(), 1, "Constant polynomial should have one coefficient.");
@@ -997,11 +1092,11 @@ fn test_compose_with_zero() {
 #[test]
 fn test_compose_with_constant() {
     // p(x) = x + 2, c(x) = 5 => p(5) = 5 + 2 = 7 ≡ 0 mod7 => zero polynomial
-    let p = Polynomial::<7>::new(vec![
+    let p = Polynomial::<FieldElement<7>>::new(vec![
         FieldElement::<7>::new(2),
         FieldElement::<7>::new(1),
     ]);
-    let constant_poly = Polynomial::<7>::new(vec![
+    let constant_poly = Polynomial::<FieldElement<7>>::new(vec![
         FieldElement::<7>::new(5),
     ]);
     let result = p.compose(&constant_poly);
@@ -1019,8 +1114,8 @@ fn test_compose_additional() {
         FieldElement::<7>::new(2),
         FieldElement::<7>::new(3),
     ];
-    let poly1 = Polynomial::<7>::new(coeffs1);
-    let poly2 = Polynomial::<7>::new(coeffs2);
+    let poly1 = Polynomial::<FieldElement<7>>::new(coeffs1);
+    let poly2 = Polynomial::<FieldElement<7>>::new(coeffs2);
     let result = poly1.compose(&poly2);
     assert_eq!(result.degree, 1, "Resultant polynomial should have degree 1.");
     assert_eq!(result.coefficients.len(), 2, "Resultant polynomial should have two coefficients.");
@@ -1042,12 +1137,12 @@ fn test_compose_additional() {
 
     #[test]
 fn test_div_rem_random_polys() {
-    let mut rng = rand::thread_rng();
+    let mut rng = ChaCha20Rng::seed_from_u64(0);
     for _ in 0..5 {
         let deg_a = rng.gen_range(0..5);
         let deg_b = rng.gen_range(0..5);
-        let poly_a = generate_random_polynomial(deg_a);
-        let poly_b = generate_random_polynomial(deg_b);
+        let poly_a = generate_random_polynomial(deg_a, &mut rng);
+        let poly_b = generate_random_polynomial(deg_b, &mut rng);
         if poly_b.is_zero() {
             continue;
         }
@@ -1066,6 +1161,18 @@ fn test_div_rem_random_polys() {
     }
 }
 
+#[test]
+fn test_div_rem_above_fast_threshold() {
+    let mut rng = ChaCha20Rng::seed_from_u64(20);
+    let n = div::FAST_DIV_THRESHOLD;
+    let a: Polynomial<crate::fields::BabyBear> = super::generate_random_polynomial(3 * n, &mut rng);
+    let b: Polynomial<crate::fields::BabyBear> = super::generate_random_polynomial(n + 1, &mut rng);
+    let (q, r) = a.div_rem(&b);
+    assert!(r.degree < b.degree);
+    assert_eq!(&(&b * &q) + &r, a);
+    assert_eq!((q, r), a.div_rem_naive(&b));
+}
+
 #[test]
 fn test_from_iter() {
     let elems = vec![
@@ -1073,7 +1180,7 @@ fn test_from_iter() {
         FieldElement::<7>::new(2),
         FieldElement::<7>::new(0),
     ];
-    let poly: Polynomial<7> = elems.into_iter().collect();
+    let poly: Polynomial<FieldElement<7>> = elems.into_iter().collect();
     assert_eq!(poly.degree, 1, "Degree should be 1 after trimming trailing zeros.");
     assert_eq!(poly.coefficients.len(), 2, "Should have two coefficients after trimming.");
     assert_eq!(
@@ -0,0 +1,240 @@
+//! Subproduct trees and fast multipoint evaluation.
+//!
+//! The tree over points `x_0, ..., x_{n-1}` stores `∏ (x - x_i)` over each
+//! contiguous half, quarter, ... of the points, down to leaves of at most
+//! [`LEAF_SIZE`] points. Evaluating `p` at every point reduces it modulo the two
+//! children of each node on the way down (the remainder tree), so one pass costs
+//! `O(M(n) log n)` instead of the `O(n * deg p)` of one Horner run per point.
+//!
+//! The tree depends only on the points, so build it once and pass it to
+//! [`evaluate_many`] for every polynomial evaluated on the same set.
+//!
+//! [`SubproductTree::interpolate`] runs the other way: with weights
+//! `w_i = y_i / Z'(x_i)`, the interpolant `sum_i w_i Z(x) / (x - x_i)` is
+//! assembled bottom-up as `f = f_left * Z_right + f_right * Z_left`.
+
+use crate::fields::Field;
+use crate::polynomial::{Polynomial, PolynomialError};
+
+/// Largest number of points in a leaf. Below this, reducing modulo the children
+/// costs about as much as Horner on every point, so leaves evaluate directly.
+pub const LEAF_SIZE: usize = 64;
+
+/// Number of points from which the two halves of a node are built and
+/// evaluated on rayon.
+pub const PARALLEL_THRESHOLD: usize = 1 << 10;
+
+/// Product tree of the linear factors `x - x_i` over a fixed point set.
+#[derive(Clone, Debug)]
+pub struct SubproductTree<F: Field> {
+    points: Vec<F>,
+    root: Node<F>,
+}
+
+#[derive(Clone, Debug)]
+struct Node<F: Field> {
+    /// `∏ (x - x_i)` over the points under this node.
+    poly: Polynomial<F>,
+    children: Option<Box<(Node<F>, Node<F>)>>,
+}
+
+impl<F: Field> Node<F> {
+    fn build(points: &[F]) -> Self {
+        if points.len() <= LEAF_SIZE {
+            let poly = points
+                .iter()
+                .fold(Polynomial::new(vec![F::one()]), |acc, x| acc * Polynomial::new(vec![-x.clone(), F::one()]));
+            return Node { poly, children: None };
+        }
+        let (lo, hi) = points.split_at(points.len() / 2);
+        let (left, right) = if points.len() >= PARALLEL_THRESHOLD {
+            rayon::join(|| Node::build(lo), || Node::build(hi))
+        } else {
+            (Node::build(lo), Node::build(hi))
+        };
+        let poly = &left.poly * &right.poly;
+        Node { poly, children: Some(Box::new((left, right))) }
+    }
+
+    /// Writes `p(x_i)` into `out` for the points under this node, where `p` is
+    /// already reduced modulo `self.poly`.
+    fn evaluate(&self, p: &Polynomial<F>, points: &[F], out: &mut [F]) {
+        let Some(children) = &self.children else {
+            for (x, y) in points.iter().zip(out.iter_mut()) {
+                *y = p.evaluate(x.clone());
+            }
+            return;
+        };
+        let (left, right) = (&children.0, &children.1);
+        let mid = points.len() / 2;
+        let (points_lo, points_hi) = points.split_at(mid);
+        let (out_lo, out_hi) = out.split_at_mut(mid);
+        let descend = |child: &Node<F>, points: &[F], out: &mut [F]| child.evaluate(&p.div_rem(&child.poly).1, points, out);
+        if points.len() >= PARALLEL_THRESHOLD {
+            rayon::join(|| descend(left, points_lo, out_lo), || descend(right, points_hi, out_hi));
+        } else {
+            descend(left, points_lo, out_lo);
+            descend(right, points_hi, out_hi);
+        }
+    }
+
+    /// `sum_i weights[i] * self.poly / (x - x_i)` over the points under this node.
+    fn combine(&self, points: &[F], weights: &[F]) -> Polynomial<F> {
+        let Some(children) = &self.children else {
+            let mut acc = Polynomial::zero();
+            for (x, w) in points.iter().zip(weights) {
+                let (mut term, _) = self.poly.synthetic_div(x);
+                term.scalar_mul(w.clone());
+                acc.add_assign(&term);
+            }
+            return acc;
+        };
+        let (left, right) = (&children.0, &children.1);
+        let mid = points.len() / 2;
+        let (points_lo, points_hi) = points.split_at(mid);
+        let (weights_lo, weights_hi) = weights.split_at(mid);
+        let (f_left, f_right) = if points.len() >= PARALLEL_THRESHOLD {
+            rayon::join(|| left.combine(points_lo, weights_lo), || right.combine(points_hi, weights_hi))
+        } else {
+            (left.combine(points_lo, weights_lo), right.combine(points_hi, weights_hi))
+        };
+        &f_left * &right.poly + &f_right * &left.poly
+    }
+}
+
+impl<F: Field> SubproductTree<F> {
+    /// Builds the tree over `points` in `O(M(n) log n)`; the points need not be distinct.
+    pub fn new(points: &[F]) -> Self {
+        SubproductTree { points: points.to_vec(), root: Node::build(points) }
+    }
+
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// The vanishing polynomial `∏ (x - x_i)` of the whole point set.
+    pub fn vanishing_polynomial(&self) -> &Polynomial<F> {
+        &self.root.poly
+    }
+
+    /// The polynomial of degree below `len()` with `f(x_i) = ys[i]`, in `O(M(n) log n)`.
+    ///
+    /// Fails with `LengthMismatch` if `ys` does not have one value per point and
+    /// with `DuplicatePoint` if the points are not distinct.
+    pub fn interpolate(&self, ys: &[F]) -> Result<Polynomial<F>, PolynomialError> {
+        if ys.len() != self.len() {
+            return Err(PolynomialError::LengthMismatch { xs: self.len(), ys: ys.len() });
+        }
+        if self.is_empty() {
+            return Ok(Polynomial::zero());
+        }
+        let mut weights = self.barycentric_weights()?;
+        F::mul_slices(&mut weights, ys);
+        Ok(self.root.combine(&self.points, &weights))
+    }
+
+    /// The weights `w_i = 1 / Z'(x_i)` shared by Lagrange interpolation and
+    /// barycentric evaluation, in `O(M(n) log n)`. Fails with `DuplicatePoint`
+    /// if the points are not distinct.
+    pub fn barycentric_weights(&self) -> Result<Vec<F>, PolynomialError> {
+        // Z'(x_i) = ∏_{j != i} (x_i - x_j), which vanishes exactly when x_i is repeated.
+        let mut weights = evaluate_many(&self.root.poly.derivative(), self);
+        if weights.iter().any(F::is_zero) {
+            return Err(PolynomialError::DuplicatePoint);
+        }
+        F::batch_inverse(&mut weights);
+        Ok(weights)
+    }
+}
+
+/// `[p(x_0), ..., p(x_{n-1})]` for the points of `tree`, in their original order.
+pub fn evaluate_many<F: Field>(p: &Polynomial<F>, tree: &SubproductTree<F>) -> Vec<F> {
+    let mut out = vec![F::zero(); tree.len()];
+    if tree.is_empty() {
+        return out;
+    }
+    let reduced = if p.degree >= tree.root.poly.degree { p.div_rem(&tree.root.poly).1 } else { p.clone() };
+    tree.root.evaluate(&reduced, &tree.points, &mut out);
+    out
+}
+
+#[cfg(test)]
+mod test_tree {
+    use super::*;
+    use crate::fields::{FieldElement, Goldilocks, Mersenne31};
+    use crate::polynomial::ops::generate_random_polynomial;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    #[test]
+    fn test_evaluate_many_matches_horner() {
+        let mut rng = ChaCha20Rng::seed_from_u64(22);
+        for (n, degree) in [(1, 5), (10, 3), (LEAF_SIZE + 1, 200), (700, 699), (PARALLEL_THRESHOLD + 300, 2000)] {
+            let points: Vec<Goldilocks> = (0..n).map(|_| Goldilocks::random_with(&mut rng)).collect();
+            let tree = SubproductTree::new(&points);
+            let p: Polynomial<Goldilocks> = generate_random_polynomial(degree, &mut rng);
+            let expected: Vec<Goldilocks> = points.iter().map(|x| p.evaluate(*x)).collect();
+            assert_eq!(evaluate_many(&p, &tree), expected, "n={n} degree={degree}");
+        }
+    }
+
+    #[test]
+    fn test_tree_is_reusable() {
+        // Mersenne31 has no large two-adic subgroup, so the products go through Karatsuba.
+        let mut rng = ChaCha20Rng::seed_from_u64(122);
+        let points: Vec<Mersenne31> = (0..300).map(|_| Mersenne31::random_with(&mut rng)).collect();
+        let tree = SubproductTree::new(&points);
+        for degree in [0, 50, 299, 1000] {
+            let p: Polynomial<Mersenne31> = generate_random_polynomial(degree, &mut rng);
+            let expected: Vec<Mersenne31> = points.iter().map(|x| p.evaluate(*x)).collect();
+            assert_eq!(p.evaluate_many(&tree), expected);
+        }
+    }
+
+    #[test]
+    fn test_vanishing_polynomial_and_edge_cases() {
+        type F = FieldElement<17>;
+        // Repeated points are allowed.
+        let points: Vec<F> = [1, 2, 2, 16].into_iter().map(F::new).collect();
+        let tree = SubproductTree::new(&points);
+        assert_eq!(tree.vanishing_polynomial().degree, 4);
+        for x in &points {
+            assert!(tree.vanishing_polynomial().evaluate(*x).is_zero());
+        }
+        assert_eq!(evaluate_many(&Polynomial::zero(), &tree), vec![F::zero(); 4]);
+
+        let empty = SubproductTree::<F>::new(&[]);
+        assert!(empty.is_empty());
+        assert!(evaluate_many(&Polynomial::new(vec![F::one()]), &empty).is_empty());
+    }
+
+    #[test]
+    fn test_interpolate_round_trip() {
+        let mut rng = ChaCha20Rng::seed_from_u64(23);
+        for n in [1, 5, LEAF_SIZE, LEAF_SIZE + 1, 600, PARALLEL_THRESHOLD + 17] {
+            let points: Vec<Goldilocks> = (0..n).map(|_| Goldilocks::random_with(&mut rng)).collect();
+            let tree = SubproductTree::new(&points);
+            let p: Polynomial<Goldilocks> = generate_random_polynomial(n - 1, &mut rng);
+            let ys = evaluate_many(&p, &tree);
+            assert_eq!(tree.interpolate(&ys).unwrap(), p, "n={n}");
+        }
+    }
+
+    #[test]
+    fn test_interpolate_errors() {
+        type F = FieldElement<17>;
+        let tree = SubproductTree::new(&[F::new(1), F::new(2), F::new(1)]);
+        let ys = [F::new(3), F::new(4), F::new(5)];
+        assert_eq!(tree.interpolate(&ys), Err(PolynomialError::DuplicatePoint));
+        assert_eq!(tree.interpolate(&ys[..2]), Err(PolynomialError::LengthMismatch { xs: 3, ys: 2 }));
+        assert_eq!(SubproductTree::<F>::new(&[]).interpolate(&[]), Ok(Polynomial::zero()));
+    }
+}
@@ -1,4 +1,4 @@
-///macro utils
+//! macro utils
 
 
 
@@ -26,10 +26,297 @@ macro_rules! field {
 // field!(Field7, 7);
 // let b = Field7::new(5);
 
+/// Declares a prime field as a newtype over `MontFieldElement`, with its
+/// parameters as named constants and a generated test module:
+///
+/// ```ignore
+/// define_field!(pub BabyBearField, modulus = 2013265921, generator = 31, tests = test_baby_bear_field);
+/// ```
+///
+/// Compilation fails unless the modulus is an odd prime and the generator is
+/// a quadratic non-residue (a necessary condition for generating the group, and
+/// what makes `TWO_ADIC_ROOT` a primitive `2^TWO_ADICITY`-th root of unity).
+/// Full primitivity needs the factors of `modulus - 1`, so the generated tests
+/// check it. `tests = ...` names the `#[cfg(test)]` module, since
+/// `macro_rules!` cannot derive one from the type name.
+#[macro_export]
+macro_rules! define_field {
+    ($vis:vis $name:ident, modulus = $modulus:expr, generator = $generator:expr, tests = $tests:ident $(,)?) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis struct $name($crate::fields::MontFieldElement<{ $modulus }>);
+
+        const _: () = {
+            use $crate::fields::montgomery::{const_is_prime, const_pow_mod};
+            let (p, g) = ($modulus as u64, $generator as u64);
+            assert!(p > 2 && const_is_prime(p), "modulus must be an odd prime");
+            assert!(g > 1 && g < p, "generator must lie in [2, modulus)");
+            assert!(const_pow_mod(g, (p - 1) / 2, p) == p - 1, "generator is a quadratic residue");
+        };
+
+        #[allow(dead_code)]
+        impl $name {
+            pub const MODULUS: u64 = $modulus;
+            pub const GENERATOR: u64 = $generator;
+            /// The largest `k` with `2^k | MODULUS - 1`.
+            pub const TWO_ADICITY: u32 = (Self::MODULUS - 1).trailing_zeros();
+            /// `GENERATOR^((MODULUS - 1) / 2^TWO_ADICITY)`, a primitive root of unity of order `2^TWO_ADICITY`.
+            pub const TWO_ADIC_ROOT: u64 = $crate::fields::montgomery::const_pow_mod(
+                Self::GENERATOR,
+                (Self::MODULUS - 1) >> Self::TWO_ADICITY,
+                Self::MODULUS,
+            );
+            /// Montgomery constants of the underlying representation.
+            pub const NEG_INV: u64 = $crate::fields::montgomery::mont_neg_inv(Self::MODULUS);
+            pub const R: u64 = $crate::fields::montgomery::mont_r(Self::MODULUS);
+            pub const R2: u64 = $crate::fields::montgomery::mont_r2(Self::MODULUS);
+
+            pub fn new(value: u64) -> Self {
+                $name($crate::fields::MontFieldElement::new(value))
+            }
+
+            pub fn zero() -> Self {
+                $name($crate::fields::MontFieldElement::zero())
+            }
+
+            pub fn one() -> Self {
+                $name($crate::fields::MontFieldElement::one())
+            }
+
+            pub fn value(&self) -> u64 {
+                self.0.value()
+            }
+
+            pub fn random() -> Self {
+                $name($crate::fields::MontFieldElement::random())
+            }
+
+            pub fn random_with<R: $crate::__private::rand_core::RngCore + ?Sized>(rng: &mut R) -> Self {
+                $name($crate::fields::MontFieldElement::random_with(rng))
+            }
+
+            pub fn pow(&self, exp: u64) -> Self {
+                $name(self.0.pow(exp))
+            }
+
+            pub fn inverse(&self) -> Self {
+                $name(self.0.inverse())
+            }
+
+            // Same signature as the other field types, even where the type is not exported.
+            #[allow(clippy::wrong_self_convention)]
+            pub fn to_bytes(&self) -> [u8; 8] {
+                self.0.to_bytes()
+            }
+        }
+
+        impl std::ops::Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                $name(self.0 + rhs.0)
+            }
+        }
+
+        impl std::ops::AddAssign for $name {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl std::ops::Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                $name(self.0 - rhs.0)
+            }
+        }
+
+        impl std::ops::SubAssign for $name {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl std::ops::Mul for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                $name(self.0 * rhs.0)
+            }
+        }
+
+        impl std::ops::MulAssign for $name {
+            fn mul_assign(&mut self, rhs: Self) {
+                self.0 *= rhs.0;
+            }
+        }
+
+        impl std::ops::Div for $name {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                $name(self.0 / rhs.0)
+            }
+        }
+
+        impl std::ops::DivAssign for $name {
+            fn div_assign(&mut self, rhs: Self) {
+                self.0 /= rhs.0;
+            }
+        }
+
+        impl std::ops::Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                $name(-self.0)
+            }
+        }
+
+        impl From<i128> for $name {
+            fn from(value: i128) -> Self {
+                $name($crate::fields::MontFieldElement::from(value))
+            }
+        }
+
+        impl From<$crate::fields::FieldElement<{ $modulus }>> for $name {
+            fn from(value: $crate::fields::FieldElement<{ $modulus }>) -> Self {
+                $name(value.into())
+            }
+        }
+
+        impl From<$name> for $crate::fields::FieldElement<{ $modulus }> {
+            fn from(value: $name) -> Self {
+                value.0.into()
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(&self.value(), f)
+            }
+        }
+
+        impl $crate::__private::rand::distributions::Distribution<$name>
+            for $crate::__private::rand::distributions::Standard
+        {
+            fn sample<R: $crate::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                $name::random_with(rng)
+            }
+        }
+
+        impl $crate::fields::Field for $name {
+            fn zero() -> Self {
+                $name::zero()
+            }
+
+            fn one() -> Self {
+                $name::one()
+            }
+
+            fn from_u64(value: u64) -> Self {
+                $name::new(value)
+            }
+
+            fn inverse(&self) -> Self {
+                $name::inverse(self)
+            }
+
+            fn pow(&self, exp: u64) -> Self {
+                $name::pow(self, exp)
+            }
+
+            fn to_bytes(&self) -> Vec<u8> {
+                $name::to_bytes(self).to_vec()
+            }
+
+            fn from_bytes(bytes: &[u8]) -> Result<Self, $crate::fields::FieldError> {
+                <$crate::fields::MontFieldElement<{ $modulus }> as $crate::fields::Field>::from_bytes(bytes).map($name)
+            }
+
+            fn random_with<R: $crate::__private::rand_core::RngCore + ?Sized>(rng: &mut R) -> Self {
+                $name::random_with(rng)
+            }
+
+            fn characteristic() -> $crate::__private::alloy::primitives::U256 {
+                $crate::__private::alloy::primitives::U256::from(Self::MODULUS)
+            }
+
+            fn two_adicity() -> u32 {
+                Self::TWO_ADICITY
+            }
+
+            fn two_adic_root_of_unity(log_n: u32) -> Option<Self> {
+                if log_n > Self::TWO_ADICITY {
+                    return None;
+                }
+                Some($name::new(Self::TWO_ADIC_ROOT).pow(1 << (Self::TWO_ADICITY - log_n)))
+            }
+
+            fn multiplicative_generator() -> Option<Self> {
+                Some($name::new(Self::GENERATOR))
+            }
+        }
+
+        #[cfg(test)]
+        mod $tests {
+            use super::$name;
+            use $crate::fields::{Field, FieldElement};
+
+            type Plain = FieldElement<{ $modulus }>;
+
+            #[test]
+            fn test_constants() {
+                assert_eq!($name::MODULUS.wrapping_mul($name::NEG_INV), u64::MAX);
+                assert_eq!($name::one().value(), 1);
+                assert_eq!($name::new($name::MODULUS), $name::zero());
+                let root = $name::new($name::TWO_ADIC_ROOT);
+                assert_eq!(root.pow(1 << $name::TWO_ADICITY), $name::one());
+                if $name::TWO_ADICITY > 0 {
+                    assert_ne!(root.pow(1 << ($name::TWO_ADICITY - 1)), $name::one());
+                }
+            }
+
+            #[test]
+            fn test_generator_is_primitive() {
+                let g = Plain::new($name::GENERATOR);
+                for (q, _) in Plain::group_order_factors().unwrap() {
+                    assert_ne!(g.pow(($name::MODULUS - 1) / q), Plain::one(), "GENERATOR^((MODULUS - 1) / {q}) = 1");
+                }
+            }
+
+            #[test]
+            fn test_matches_field_element() {
+                let m = $name::MODULUS;
+                for (x, y) in [(3, 5), (m - 1, m - 2), (m / 3, m / 7), (0, 1)] {
+                    let (a, b) = ($name::new(x), $name::new(y));
+                    let (c, d) = (Plain::new(x), Plain::new(y));
+                    assert_eq!(Plain::from(a + b), c + d);
+                    assert_eq!(Plain::from(a - b), c - d);
+                    assert_eq!(Plain::from(a * b), c * d);
+                    assert_eq!(Plain::from(-a), -c);
+                    if y != 0 {
+                        assert_eq!(Plain::from(a / b), c / d);
+                    }
+                }
+            }
+
+            #[test]
+            fn test_field_trait() {
+                let a = $name::new(12345);
+                assert_eq!(<$name as Field>::from_bytes(&Field::to_bytes(&a)), Ok(a));
+                assert_eq!($name::from(-1i128), -$name::one());
+                let root = $name::two_adic_root_of_unity(1).unwrap();
+                assert_eq!(root, -$name::one());
+                assert_eq!($name::two_adic_root_of_unity($name::TWO_ADICITY + 1), None);
+            }
+        }
+    };
+}
 
 ///logger 
 use tracing_subscriber::{
-    fmt::{self, format::Writer, time::ChronoLocal, FmtContext, FormatEvent, FormatFields},
+    fmt::{self, format::Writer, FmtContext, FormatEvent, FormatFields},
     layer::SubscriberExt,
     util::SubscriberInitExt,
     EnvFilter,
@@ -38,7 +325,6 @@ use tracing_subscriber::{
 use tracing_core::{Subscriber,Event};
 use tracing_appender::rolling;
 use chrono::Local;
-use tracing_subscriber::fmt::time::FormatTime;
 use std::fmt as fmmt;
 
 /// `[timestamp] [LEVEL] [thread THREAD_ID] file:line - message`
@@ -101,3 +387,16 @@ pub fn setup_tracing() {
         .with(file_layer)
         .init();
 }
+
+#[cfg(test)]
+mod test_define_field {
+    define_field!(pub BabyBearField, modulus = 2013265921, generator = 31, tests = test_baby_bear_field);
+    define_field!(Small97, modulus = 97, generator = 5, tests = test_small_97);
+
+    #[test]
+    fn test_parameters() {
+        assert_eq!(BabyBearField::TWO_ADICITY, 27);
+        assert_eq!(Small97::TWO_ADICITY, 5);
+        assert_eq!(Small97::new(5).pow(48), Small97::new(96));
+    }
+}